@@ -1,4 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use zagreb_lib::Graph;
 
 // Creates a deterministic graph with a specified pattern of edges
@@ -18,66 +20,6 @@ fn create_deterministic_graph(n: usize, density_factor: usize) -> Graph {
     graph
 }
 
-fn create_complete_graph(n: usize) -> Graph {
-    let mut graph = Graph::new(n);
-
-    for i in 0..n {
-        for j in (i + 1)..n {
-            let _ = graph.add_edge(i, j);
-        }
-    }
-
-    graph
-}
-
-fn create_cycle_graph(n: usize) -> Graph {
-    let mut graph = Graph::new(n);
-
-    for i in 0..n {
-        let j = (i + 1) % n;
-        let _ = graph.add_edge(i, j);
-    }
-
-    graph
-}
-
-fn create_star_graph(n: usize) -> Graph {
-    let mut graph = Graph::new(n);
-
-    for i in 1..n {
-        let _ = graph.add_edge(0, i);
-    }
-
-    graph
-}
-
-fn create_petersen_graph() -> Graph {
-    let mut graph = Graph::new(10);
-
-    // Add outer cycle edges (pentagon)
-    let _ = graph.add_edge(0, 1);
-    let _ = graph.add_edge(1, 2);
-    let _ = graph.add_edge(2, 3);
-    let _ = graph.add_edge(3, 4);
-    let _ = graph.add_edge(4, 0);
-
-    // Add spoke edges (connecting outer and inner vertices)
-    let _ = graph.add_edge(0, 5);
-    let _ = graph.add_edge(1, 6);
-    let _ = graph.add_edge(2, 7);
-    let _ = graph.add_edge(3, 8);
-    let _ = graph.add_edge(4, 9);
-
-    // Add inner pentagram edges
-    let _ = graph.add_edge(5, 7);
-    let _ = graph.add_edge(7, 9);
-    let _ = graph.add_edge(9, 6);
-    let _ = graph.add_edge(6, 8);
-    let _ = graph.add_edge(8, 5);
-
-    graph
-}
-
 fn bench_graph_creation(c: &mut Criterion) {
     let mut group = c.benchmark_group("graph_creation");
 
@@ -88,20 +30,20 @@ fn bench_graph_creation(c: &mut Criterion) {
         });
 
         group.bench_with_input(BenchmarkId::new("complete", size), size, |b, &size| {
-            b.iter(|| create_complete_graph(size));
+            b.iter(|| Graph::complete(size));
         });
 
         group.bench_with_input(BenchmarkId::new("cycle", size), size, |b, &size| {
-            b.iter(|| create_cycle_graph(size));
+            b.iter(|| Graph::cycle(size));
         });
 
         group.bench_with_input(BenchmarkId::new("star", size), size, |b, &size| {
-            b.iter(|| create_star_graph(size));
+            b.iter(|| Graph::star(size));
         });
     }
 
     group.bench_function("petersen", |b| {
-        b.iter(|| create_petersen_graph());
+        b.iter(Graph::petersen);
     });
 
     group.finish();
@@ -113,9 +55,9 @@ fn bench_zagreb_index(c: &mut Criterion) {
     // Use smaller sizes to speed up benchmarks
     for size in [10, 30, 50, 100].iter() {
         let determ_graph = create_deterministic_graph(*size, 3);
-        let complete_graph = create_complete_graph(*size);
-        let cycle_graph = create_cycle_graph(*size);
-        let star_graph = create_star_graph(*size);
+        let complete_graph = Graph::complete(*size);
+        let cycle_graph = Graph::cycle(*size);
+        let star_graph = Graph::star(*size);
 
         group.bench_with_input(
             BenchmarkId::new("deterministic", size),
@@ -142,7 +84,7 @@ fn bench_zagreb_index(c: &mut Criterion) {
         });
     }
 
-    let petersen_graph = create_petersen_graph();
+    let petersen_graph = Graph::petersen();
     group.bench_function("petersen", |b| {
         b.iter(|| black_box(&petersen_graph).first_zagreb_index());
     });
@@ -156,9 +98,9 @@ fn bench_hamiltonian_checks(c: &mut Criterion) {
     // Use much smaller sizes for these intensive checks
     for size in [10, 15, 20].iter() {
         let determ_graph = create_deterministic_graph(*size, 3);
-        let complete_graph = create_complete_graph(*size);
-        let cycle_graph = create_cycle_graph(*size);
-        let star_graph = create_star_graph(*size);
+        let complete_graph = Graph::complete(*size);
+        let cycle_graph = Graph::cycle(*size);
+        let star_graph = Graph::star(*size);
 
         group.bench_with_input(
             BenchmarkId::new("is_hamiltonian/deterministic", size),
@@ -201,7 +143,7 @@ fn bench_hamiltonian_checks(c: &mut Criterion) {
         );
     }
 
-    let petersen_graph = create_petersen_graph();
+    let petersen_graph = Graph::petersen();
     group.bench_function("is_hamiltonian/petersen", |b| {
         b.iter(|| black_box(&petersen_graph).is_likely_hamiltonian());
     });
@@ -231,7 +173,7 @@ fn bench_connectivity_checks(c: &mut Criterion) {
         }
     }
 
-    let petersen_graph = create_petersen_graph();
+    let petersen_graph = Graph::petersen();
     for k in [1, 2, 3].iter() {
         group.bench_function(format!("is_{}_connected/petersen", k), |b| {
             b.iter(|| black_box(&petersen_graph).is_k_connected(*k));
@@ -247,7 +189,7 @@ fn bench_independence_number(c: &mut Criterion) {
     // Use very small sizes for these intensive checks
     for size in [10, 12, 15].iter() {
         let determ_graph = create_deterministic_graph(*size, 3);
-        let cycle_graph = create_cycle_graph(*size);
+        let cycle_graph = Graph::cycle(*size);
 
         group.bench_with_input(
             BenchmarkId::new("deterministic", size),
@@ -262,7 +204,7 @@ fn bench_independence_number(c: &mut Criterion) {
         });
     }
 
-    let petersen_graph = create_petersen_graph();
+    let petersen_graph = Graph::petersen();
     group.bench_function("petersen", |b| {
         b.iter(|| black_box(&petersen_graph).independence_number_approx());
     });
@@ -270,6 +212,25 @@ fn bench_independence_number(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_independence_number_large(c: &mut Criterion) {
+    let mut group = c.benchmark_group("independence_number_large");
+    group.sample_size(10);
+
+    // The greedy approximation is polynomial, so it can be timed on realistic sizes
+    for size in [500, 2_000].iter() {
+        let determ_graph = create_deterministic_graph(*size, 50);
+        group.bench_with_input(
+            BenchmarkId::new("deterministic", size),
+            &determ_graph,
+            |b, graph| {
+                b.iter(|| black_box(graph).independence_number_approx());
+            },
+        );
+    }
+
+    group.finish();
+}
+
 fn bench_upper_bound(c: &mut Criterion) {
     let mut group = c.benchmark_group("zagreb_upper_bound");
 
@@ -286,7 +247,7 @@ fn bench_upper_bound(c: &mut Criterion) {
         );
     }
 
-    let petersen_graph = create_petersen_graph();
+    let petersen_graph = Graph::petersen();
     group.bench_function("petersen", |b| {
         b.iter(|| black_box(&petersen_graph).zagreb_upper_bound());
     });
@@ -294,6 +255,110 @@ fn bench_upper_bound(c: &mut Criterion) {
     group.finish();
 }
 
+// Exact k-connectivity runs the vertex-disjoint path search for every pair of vertices
+fn bench_vertex_disjoint_paths(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vertex_disjoint_paths");
+
+    let petersen_graph = Graph::petersen();
+    group.bench_function("is_3_connected_exact/petersen", |b| {
+        b.iter(|| black_box(&petersen_graph).is_k_connected_exact(3));
+    });
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let dense_graph = Graph::erdos_renyi(50, 0.7, &mut rng);
+    let k = dense_graph.min_degree();
+    group.bench_function(format!("is_{}_connected_exact/dense_50", k), |b| {
+        b.iter(|| black_box(&dense_graph).is_k_connected_exact(k));
+    });
+
+    group.finish();
+}
+
+// Large graphs, where per-vertex adjacency lookups dominate creation and Zagreb index timings
+fn bench_large_graphs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("large_graphs");
+    group.sample_size(20);
+
+    for size in [1_000, 5_000].iter() {
+        group.bench_with_input(BenchmarkId::new("creation/cycle", size), size, |b, &size| {
+            b.iter(|| Graph::cycle(size));
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("creation/deterministic", size),
+            size,
+            |b, &size| {
+                b.iter(|| create_deterministic_graph(size, 50));
+            },
+        );
+
+        let determ_graph = create_deterministic_graph(*size, 50);
+        group.bench_with_input(
+            BenchmarkId::new("zagreb_index/deterministic", size),
+            &determ_graph,
+            |b, graph| {
+                b.iter(|| black_box(graph).first_zagreb_index());
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_degree_scans(c: &mut Criterion) {
+    let mut group = c.benchmark_group("degree_scans");
+    group.sample_size(20);
+
+    // 10,000 vertices and about 5 million edges
+    let dense_graph = create_deterministic_graph(10_000, 10);
+
+    // min_degree and max_degree are cached after the first call, so time the scans themselves;
+    // run with `--features rayon` to compare against the parallel versions
+    group.bench_function("min_degree/serial", |b| {
+        b.iter(|| black_box(&dense_graph).min_degree_serial());
+    });
+
+    group.bench_function("max_degree/serial", |b| {
+        b.iter(|| black_box(&dense_graph).max_degree_serial());
+    });
+
+    #[cfg(feature = "rayon")]
+    {
+        group.bench_function("min_degree/parallel", |b| {
+            b.iter(|| black_box(&dense_graph).min_degree_parallel());
+        });
+
+        group.bench_function("max_degree/parallel", |b| {
+            b.iter(|| black_box(&dense_graph).max_degree_parallel());
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_repeated_analysis(c: &mut Criterion) {
+    let mut group = c.benchmark_group("repeated_analysis");
+    group.sample_size(20);
+
+    // Degree summaries are cached, so only the first analysis pays for the scans
+    let graph = create_deterministic_graph(5_000, 50);
+
+    group.bench_function("degree_summaries/5000", |b| {
+        b.iter(|| {
+            let graph = black_box(&graph);
+            (0..10)
+                .map(|_| graph.first_zagreb_index() + graph.min_degree() + graph.max_degree())
+                .sum::<usize>()
+        });
+    });
+
+    group.bench_function("is_likely_hamiltonian/5000", |b| {
+        b.iter(|| black_box(&graph).is_likely_hamiltonian());
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_graph_creation,
@@ -301,6 +366,11 @@ criterion_group!(
     bench_hamiltonian_checks,
     bench_connectivity_checks,
     bench_independence_number,
-    bench_upper_bound
+    bench_independence_number_large,
+    bench_upper_bound,
+    bench_vertex_disjoint_paths,
+    bench_large_graphs,
+    bench_degree_scans,
+    bench_repeated_analysis
 );
 criterion_main!(benches);
@@ -6,7 +6,7 @@ fn main() {
     println!("Analyzing properties of the Petersen graph");
 
     // Create the Petersen graph
-    let graph = create_petersen_graph();
+    let graph = Graph::petersen();
 
     // Analyze the graph
     analyze_graph_properties(&graph);
@@ -18,53 +18,28 @@ fn main() {
     explain_non_hamiltonian_property();
 }
 
-/// Create a Petersen graph
-fn create_petersen_graph() -> Graph {
-    let mut graph = Graph::new(10);
-
-    // Add outer cycle edges (pentagon)
-    graph.add_edge(0, 1).unwrap();
-    graph.add_edge(1, 2).unwrap();
-    graph.add_edge(2, 3).unwrap();
-    graph.add_edge(3, 4).unwrap();
-    graph.add_edge(4, 0).unwrap();
-
-    // Add spoke edges (connecting outer and inner vertices)
-    graph.add_edge(0, 5).unwrap();
-    graph.add_edge(1, 6).unwrap();
-    graph.add_edge(2, 7).unwrap();
-    graph.add_edge(3, 8).unwrap();
-    graph.add_edge(4, 9).unwrap();
-
-    // Add inner pentagram edges
-    graph.add_edge(5, 7).unwrap();
-    graph.add_edge(7, 9).unwrap();
-    graph.add_edge(9, 6).unwrap();
-    graph.add_edge(6, 8).unwrap();
-    graph.add_edge(8, 5).unwrap();
-
-    graph
-}
-
 /// Analyze the basic properties of the graph
 fn analyze_graph_properties(graph: &Graph) {
     println!("\nBasic properties:");
     println!("Vertices: {}", graph.vertex_count());
     println!("Edges: {}", graph.edge_count());
+    println!("Average degree: {:.2}", graph.average_degree());
+    println!("Density: {:.3}", graph.density());
     println!("Minimum degree: {}", graph.min_degree());
     println!("Maximum degree: {}", graph.max_degree());
     println!("First Zagreb index: {}", graph.first_zagreb_index());
 
     // Check connectivity
     println!("\nConnectivity properties:");
+    let connectivity = graph.max_k_connectivity(5);
     for k in 1..=5 {
-        println!("{}-connected: {}", k, graph.is_k_connected(k, false));
+        println!("{}-connected: {}", k, k <= connectivity);
     }
 
     // Check Hamiltonian and traceable properties
     println!("\nHamiltonian properties:");
-    println!("Is likely Hamiltonian: {}", graph.is_likely_hamiltonian(false));
-    println!("Is likely traceable: {}", graph.is_likely_traceable(false));
+    println!("Is likely Hamiltonian: {}", graph.is_likely_hamiltonian());
+    println!("Is likely traceable: {}", graph.is_likely_traceable());
 
     // Calculate independence number approximation
     println!(
@@ -0,0 +1,293 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{squared_degree_sum, Graph, GraphError};
+
+/// A directed graph represented as out- and in-adjacency lists
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiGraph {
+    /// Out-neighbors of each vertex
+    out_edges: Vec<HashSet<usize>>,
+    /// In-neighbors of each vertex
+    in_edges: Vec<HashSet<usize>>,
+    /// Number of vertices in the graph
+    n_vertices: usize,
+    /// Number of arcs in the graph
+    n_arcs: usize,
+}
+
+impl DiGraph {
+    /// Create a new empty directed graph with n vertices
+    pub fn new(n: usize) -> Self {
+        DiGraph {
+            out_edges: vec![HashSet::new(); n],
+            in_edges: vec![HashSet::new(); n],
+            n_vertices: n,
+            n_arcs: 0,
+        }
+    }
+
+    /// Add an arc from `from` to `to`
+    /// Adding an arc that already exists does nothing; the reverse arc is a different arc
+    pub fn add_arc(&mut self, from: usize, to: usize) -> Result<(), GraphError> {
+        if from >= self.n_vertices {
+            return Err(GraphError::VertexOutOfBounds(from));
+        }
+        if to >= self.n_vertices {
+            return Err(GraphError::VertexOutOfBounds(to));
+        }
+        if from == to {
+            return Err(GraphError::SelfLoop(from));
+        }
+
+        if self.out_edges[from].insert(to) {
+            self.in_edges[to].insert(from);
+            self.n_arcs += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Check if there is an arc from `from` to `to`
+    pub fn has_arc(&self, from: usize, to: usize) -> bool {
+        from < self.n_vertices && self.out_edges[from].contains(&to)
+    }
+
+    /// Get the number of arcs leaving v
+    pub fn out_degree(&self, v: usize) -> Result<usize, GraphError> {
+        self.out_edges
+            .get(v)
+            .map(HashSet::len)
+            .ok_or(GraphError::VertexOutOfBounds(v))
+    }
+
+    /// Get the number of arcs entering v
+    pub fn in_degree(&self, v: usize) -> Result<usize, GraphError> {
+        self.in_edges
+            .get(v)
+            .map(HashSet::len)
+            .ok_or(GraphError::VertexOutOfBounds(v))
+    }
+
+    /// Calculate the first Zagreb index over out-degrees, the sum of d⁺(v)²
+    pub fn first_zagreb_index(&self) -> usize {
+        squared_degree_sum(&self.out_edges)
+    }
+
+    /// Forget arc directions, joining u and v whenever there is an arc either way
+    pub fn to_undirected(&self) -> Graph {
+        let mut graph = Graph::new(self.n_vertices);
+        for (u, targets) in self.out_edges.iter().enumerate() {
+            for &v in targets {
+                graph.add_edge(u, v).unwrap();
+            }
+        }
+        graph
+    }
+
+    /// Get the fraction of arcs whose reverse arc is also present
+    /// Returns 0.0 if there are no arcs
+    pub fn reciprocity(&self) -> f64 {
+        if self.n_arcs == 0 {
+            return 0.0;
+        }
+
+        let mutual = (0..self.n_vertices)
+            .map(|u| self.out_edges[u].intersection(&self.in_edges[u]).count())
+            .sum::<usize>();
+        mutual as f64 / self.n_arcs as f64
+    }
+
+    /// Get the strongly connected components using Tarjan's algorithm
+    /// Each component is sorted, and components are ordered by their smallest vertex
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let n = self.n_vertices;
+        let out_neighbors: Vec<Vec<usize>> = self
+            .out_edges
+            .iter()
+            .map(|targets| {
+                let mut sorted: Vec<usize> = targets.iter().cloned().collect();
+                sorted.sort_unstable();
+                sorted
+            })
+            .collect();
+
+        let mut index = vec![usize::MAX; n];
+        let mut low_link = vec![0; n];
+        let mut on_stack = vec![false; n];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut next_index = 0;
+
+        for root in 0..n {
+            if index[root] != usize::MAX {
+                continue;
+            }
+
+            // Explicit DFS stack of (vertex, next out-neighbor position) to avoid deep recursion
+            let mut call_stack = vec![(root, 0)];
+            index[root] = next_index;
+            low_link[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some(&mut (u, ref mut position)) = call_stack.last_mut() {
+                if let Some(&v) = out_neighbors[u].get(*position) {
+                    *position += 1;
+                    if index[v] == usize::MAX {
+                        index[v] = next_index;
+                        low_link[v] = next_index;
+                        next_index += 1;
+                        stack.push(v);
+                        on_stack[v] = true;
+                        call_stack.push((v, 0));
+                    } else if on_stack[v] {
+                        low_link[u] = low_link[u].min(index[v]);
+                    }
+                    continue;
+                }
+
+                call_stack.pop();
+                if let Some(&(parent, _)) = call_stack.last() {
+                    low_link[parent] = low_link[parent].min(low_link[u]);
+                }
+
+                // u is the root of a component: pop it off the vertex stack
+                if low_link[u] == index[u] {
+                    let mut component = Vec::new();
+                    while let Some(w) = stack.pop() {
+                        on_stack[w] = false;
+                        component.push(w);
+                        if w == u {
+                            break;
+                        }
+                    }
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+
+        components.sort_unstable_by_key(|component| component[0]);
+        components
+    }
+
+    /// Check if every vertex can reach every other vertex along arcs
+    pub fn is_strongly_connected(&self) -> bool {
+        self.strongly_connected_components().len() <= 1
+    }
+
+    /// Get the number of vertices
+    pub fn vertex_count(&self) -> usize {
+        self.n_vertices
+    }
+
+    /// Get the number of arcs
+    pub fn arc_count(&self) -> usize {
+        self.n_arcs
+    }
+}
+
+/// Get the fraction of directed connections in a raw adjacency map that are mutual
+///
+/// `adjacency[u]` holds the vertices u sees. A connection u → v is mutual if v also sees u.
+/// Useful for checking how much information is lost before collapsing observed connections
+/// into an undirected `Graph`. Self-connections are ignored; returns 0.0 if there are none.
+pub fn reciprocity(adjacency: &HashMap<usize, HashSet<usize>>) -> f64 {
+    let mut total = 0;
+    let mut mutual = 0;
+
+    for (&u, targets) in adjacency {
+        for &v in targets.iter().filter(|&&v| v != u) {
+            total += 1;
+            if adjacency.get(&v).is_some_and(|back| back.contains(&u)) {
+                mutual += 1;
+            }
+        }
+    }
+
+    if total == 0 {
+        return 0.0;
+    }
+    mutual as f64 / total as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directed_cycle_is_one_component() {
+        let mut cycle = DiGraph::new(5);
+        for i in 0..5 {
+            cycle.add_arc(i, (i + 1) % 5).unwrap();
+        }
+
+        assert_eq!(cycle.strongly_connected_components(), vec![vec![0, 1, 2, 3, 4]]);
+        assert!(cycle.is_strongly_connected());
+        assert_eq!(cycle.first_zagreb_index(), 5);
+        assert_eq!(cycle.to_undirected().first_zagreb_index(), 20);
+    }
+
+    #[test]
+    fn test_dag_components_are_singletons() {
+        // A diamond 0 → {1, 2} → 3
+        let mut dag = DiGraph::new(4);
+        for &(u, v) in &[(0, 1), (0, 2), (1, 3), (2, 3)] {
+            dag.add_arc(u, v).unwrap();
+        }
+
+        assert_eq!(
+            dag.strongly_connected_components(),
+            vec![vec![0], vec![1], vec![2], vec![3]]
+        );
+        assert!(!dag.is_strongly_connected());
+        assert_eq!(dag.out_degree(0), Ok(2));
+        assert_eq!(dag.in_degree(0), Ok(0));
+        assert_eq!(dag.in_degree(3), Ok(2));
+        assert_eq!(dag.out_degree(4), Err(GraphError::VertexOutOfBounds(4)));
+    }
+
+    #[test]
+    fn test_mixed_components() {
+        // Cycle {0, 1, 2} feeding the cycle {3, 4}, plus a sink 5
+        let mut graph = DiGraph::new(6);
+        for &(u, v) in &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 3), (4, 5)] {
+            graph.add_arc(u, v).unwrap();
+        }
+
+        assert_eq!(
+            graph.strongly_connected_components(),
+            vec![vec![0, 1, 2], vec![3, 4], vec![5]]
+        );
+
+        // Arcs are directed: the reverse arc is separate, repeats are ignored
+        assert!(graph.has_arc(0, 1));
+        assert!(!graph.has_arc(1, 0));
+        graph.add_arc(0, 1).unwrap();
+        assert_eq!(graph.arc_count(), 7);
+        assert_eq!(graph.add_arc(2, 2), Err(GraphError::SelfLoop(2)));
+    }
+
+    #[test]
+    fn test_reciprocity() {
+        // 0 ↔ 1 is mutual; 0 → 2 and 1 → 2 are not seen back
+        let adjacency: HashMap<usize, HashSet<usize>> = HashMap::from([
+            (0, HashSet::from([1, 2])),
+            (1, HashSet::from([0, 2])),
+            (2, HashSet::new()),
+        ]);
+        assert_eq!(reciprocity(&adjacency), 0.5);
+
+        let mut graph = DiGraph::new(3);
+        for (&u, targets) in &adjacency {
+            for &v in targets {
+                graph.add_arc(u, v).unwrap();
+            }
+        }
+        assert_eq!(graph.reciprocity(), 0.5);
+
+        assert_eq!(reciprocity(&HashMap::new()), 0.0);
+        assert_eq!(DiGraph::new(2).reciprocity(), 0.0);
+    }
+}
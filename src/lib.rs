@@ -1,6 +1,11 @@
 // zagreb-lib/src/lib.rs
+use rand::Rng;
 use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::sync::OnceLock;
+
+mod digraph;
+pub use digraph::{reciprocity, DiGraph};
 
 #[cfg(target_arch = "wasm32")]
 mod wasm;
@@ -10,1976 +15,7992 @@ pub use wasm::*;
 
 /// A graph represented as an adjacency list
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "GraphData", try_from = "GraphData"))]
 pub struct Graph {
-    /// Adjacency list representation of the graph
-    edges: HashMap<usize, HashSet<usize>>,
+    /// Adjacency list representation of the graph, indexed by vertex
+    edges: Vec<HashSet<usize>>,
     /// Number of vertices in the graph
     n_vertices: usize,
     /// Number of edges in the graph
     n_edges: usize,
+    /// Weights of edges added with a weight other than 1.0, keyed by `(min, max)` endpoint
+    weights: HashMap<(usize, usize), f64>,
+    /// First Zagreb index, updated incrementally by every method that changes the edges
+    zagreb_index: usize,
+    /// Degree summaries computed on first use and reset whenever the edges change
+    degree_stats: OnceLock<DegreeStats>,
 }
 
-impl fmt::Debug for Graph {
+/// Degree summaries shared by the Hamiltonicity checks
+#[derive(Debug, Clone, Copy)]
+struct DegreeStats {
+    min_degree: usize,
+    max_degree: usize,
+}
+
+/// Errors returned by graph construction and manipulation methods
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphError {
+    /// A vertex index is not smaller than the number of vertices
+    VertexOutOfBounds(usize),
+    /// An edge from a vertex to itself was requested
+    SelfLoop(usize),
+    /// A vertex was listed more than once where distinct vertices are required
+    DuplicateVertex(usize),
+    /// The input data does not have the expected shape or values
+    InvalidInput(String),
+}
+
+impl fmt::Display for GraphError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "Graph {{")?;
-        writeln!(f, "  vertices: {},", self.n_vertices)?;
-        writeln!(f, "  edges: {},", self.n_edges)?;
-        writeln!(f, "  adjacency list: {{")?;
-        for v in 0..self.n_vertices {
-            let neighbors: Vec<usize> = self.edges.get(&v).unwrap_or(&HashSet::new()).iter().cloned().collect();
-            writeln!(f, "    {}: {:?},", v, neighbors)?;
+        match self {
+            GraphError::VertexOutOfBounds(v) => write!(f, "Vertex index {} out of bounds", v),
+            GraphError::SelfLoop(v) => write!(f, "Self-loop at vertex {} is not allowed", v),
+            GraphError::DuplicateVertex(v) => write!(f, "Vertex {} is listed more than once", v),
+            GraphError::InvalidInput(message) => write!(f, "Invalid input: {}", message),
         }
-        writeln!(f, "  }}")?;
-        write!(f, "}}")
     }
 }
 
-impl Graph {
-    /// Create a new empty graph with n vertices
-    pub fn new(n: usize) -> Self {
-        let mut edges = HashMap::new();
-        for i in 0..n {
-            edges.insert(i, HashSet::new());
-        }
+impl std::error::Error for GraphError {}
 
-        Graph {
-            edges,
-            n_vertices: n,
-            n_edges: 0,
+/// Serialized form of a `Graph`: the vertex count and each edge once
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct GraphData {
+    n_vertices: usize,
+    edges: Vec<(usize, usize)>,
+    /// Edges whose weight is not 1.0; omitted for unweighted graphs
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    weights: Vec<(usize, usize, f64)>,
+}
+
+#[cfg(feature = "serde")]
+impl From<Graph> for GraphData {
+    fn from(graph: Graph) -> Self {
+        let mut weights: Vec<(usize, usize, f64)> = graph
+            .weights
+            .iter()
+            .map(|(&(u, v), &w)| (u, v, w))
+            .collect();
+        weights.sort_by_key(|&(u, v, _)| (u, v));
+
+        GraphData {
+            n_vertices: graph.n_vertices,
+            edges: graph.edge_list(),
+            weights,
         }
     }
+}
 
-    /// Add an edge between vertices u and v
-    pub fn add_edge(&mut self, u: usize, v: usize) -> Result<(), &'static str> {
-        if u >= self.n_vertices || v >= self.n_vertices {
-            return Err("Vertex index out of bounds");
-        }
+#[cfg(feature = "serde")]
+impl TryFrom<GraphData> for Graph {
+    type Error = GraphError;
 
-        if u == v {
-            return Err("Self-loops are not allowed");
+    fn try_from(data: GraphData) -> Result<Self, Self::Error> {
+        let mut graph = Graph::new(data.n_vertices);
+        for (u, v) in data.edges {
+            graph.try_add_edge(u, v)?;
         }
-
-        // Check if the edge already exists
-        if self.edges.get(&u).unwrap().contains(&v) {
-            return Ok(()); // Edge already exists
+        for (u, v, w) in data.weights {
+            graph
+                .add_weighted_edge(u, v, w)
+                .map_err(|e| GraphError::InvalidInput(e.to_string()))?;
         }
+        Ok(graph)
+    }
+}
 
-        // Add the edge in both directions (undirected graph)
-        self.edges.get_mut(&u).unwrap().insert(v);
-        self.edges.get_mut(&v).unwrap().insert(u);
-        self.n_edges += 1;
+/// Version of the document layout produced by `Graph::to_analysis_json`
+#[cfg(feature = "serde")]
+pub const ANALYSIS_SCHEMA_VERSION: u32 = 1;
+
+/// Self-describing analysis document bundling a graph with its metrics
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct AnalysisDocument<'a> {
+    schema_version: u32,
+    graph: &'a Graph,
+    report: GraphReport,
+}
 
-        Ok(())
-    }
+/// Snapshot of the main metrics computed for a graph
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GraphReport {
+    /// Number of vertices
+    pub vertex_count: usize,
+    /// Number of edges
+    pub edge_count: usize,
+    /// Minimum vertex degree
+    pub min_degree: usize,
+    /// Maximum vertex degree
+    pub max_degree: usize,
+    /// First Zagreb index
+    pub first_zagreb_index: usize,
+    /// Wiener index, or None if the graph is disconnected
+    pub wiener_index: Option<usize>,
+    /// Edge connectivity λ(G)
+    pub edge_connectivity: usize,
+    /// Approximate independence number
+    pub independence_number: usize,
+    /// Result of `is_likely_hamiltonian_with(false)`
+    pub is_likely_hamiltonian: bool,
+    /// Result of `is_likely_traceable_with(false)`
+    pub is_likely_traceable: bool,
+    /// Theorem 3 upper bound on the Zagreb index
+    pub zagreb_upper_bound: f64,
+}
 
-    /// Get the degree of a vertex
-    pub fn degree(&self, v: usize) -> Result<usize, &'static str> {
-        if v >= self.n_vertices {
-            return Err("Vertex index out of bounds");
+/// Label of a node in the block-cut tree returned by `Graph::block_cut_tree`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockCutNode {
+    /// A block (maximal biconnected subgraph) with its sorted vertex set
+    Block(Vec<usize>),
+    /// An articulation (cut) vertex of the original graph
+    CutVertex(usize),
+}
+
+/// Working state for Tarjan's biconnected components search
+struct BiconnectedSearch {
+    discovery: Vec<usize>,
+    low: Vec<usize>,
+    timer: usize,
+    edge_stack: Vec<(usize, usize)>,
+    is_cut_vertex: Vec<bool>,
+    blocks: Vec<Vec<usize>>,
+}
+
+/// Working state for an augmenting-path search in Edmonds' blossom algorithm
+struct BlossomSearch {
+    /// Partner of each vertex in the current matching
+    mate: Vec<Option<usize>>,
+    /// Predecessor of each odd vertex in the alternating tree
+    parent: Vec<Option<usize>>,
+    /// Base of the contracted blossom containing each vertex
+    base: Vec<usize>,
+    /// Whether each vertex is an even vertex of the tree
+    used: Vec<bool>,
+    /// Bases of the blossom being contracted
+    blossom: Vec<bool>,
+}
+
+/// Part of a graph not yet embedded by the planarity test, attached to the embedded part
+struct PlanarFragment {
+    /// Unembedded vertices of the fragment; empty for a single unembedded edge
+    component: Vec<usize>,
+    /// Embedded vertices the fragment connects to, sorted
+    attachments: Vec<usize>,
+}
+
+/// Split a face along a path between two of its vertices
+/// Returns the face running from the path's start to its end and back along the path, then
+/// the face running from its end to its start and back along the path.
+fn split_face(face: &[usize], path: &[usize]) -> (Vec<usize>, Vec<usize>) {
+    let (a, b) = (path[0], path[path.len() - 1]);
+    let i = face.iter().position(|&x| x == a).unwrap();
+    let j = face.iter().position(|&x| x == b).unwrap();
+    let walk = |from: usize, to: usize| {
+        let mut side = vec![face[from]];
+        let mut k = from;
+        while k != to {
+            k = (k + 1) % face.len();
+            side.push(face[k]);
         }
+        side
+    };
+
+    let inner = &path[1..path.len() - 1];
+    let mut first = walk(i, j);
+    first.extend(inner.iter().rev());
+    let mut second = walk(j, i);
+    second.extend(inner.iter());
+    (first, second)
+}
 
-        Ok(self.edges.get(&v).unwrap().len())
-    }
+/// Largest graph for which the disjoint-path search uses a `BitsetAdjacency` workspace
+/// The bitset needs n²/8 bytes, about 2 MiB at this size
+const BITSET_MAX_VERTICES: usize = 4096;
+
+/// Mutable copy of a graph's adjacency used while counting vertex-disjoint paths
+trait DisjointPathWorkspace {
+    /// Find a shortest path from s to t in the current workspace
+    fn find_path(&self, s: usize, t: usize) -> Option<Vec<usize>>;
+    /// Remove the edge between u and v
+    fn remove_edge(&mut self, u: usize, v: usize);
+    /// Remove every edge incident to v
+    fn isolate(&mut self, v: usize);
+}
 
-    /// Calculate the first Zagreb index of the graph
-    pub fn first_zagreb_index(&self) -> usize {
-        let mut sum = 0;
+/// Adjacency matrix stored as one row of 64-bit words per vertex
+/// Neighbor removal is a bit clear, and BFS skips visited vertices a word at a time
+struct BitsetAdjacency {
+    words_per_row: usize,
+    rows: Vec<u64>,
+}
 
-        for v in 0..self.n_vertices {
-            let deg = self.edges.get(&v).unwrap().len();
-            sum += deg * deg;
+impl BitsetAdjacency {
+    fn from_graph(graph: &Graph) -> Self {
+        let words_per_row = graph.n_vertices.div_ceil(64);
+        let mut adjacency = BitsetAdjacency {
+            words_per_row,
+            rows: vec![0; graph.n_vertices * words_per_row],
+        };
+
+        for (u, neighbors) in graph.edges.iter().enumerate() {
+            for &v in neighbors {
+                adjacency.rows[u * words_per_row + v / 64] |= 1 << (v % 64);
+            }
         }
 
-        sum
+        adjacency
     }
 
-    /// Get the minimum degree of the graph
-    pub fn min_degree(&self) -> usize {
-        (0..self.n_vertices)
-            .map(|v| self.edges.get(&v).unwrap().len())
-            .min()
-            .unwrap_or(0)
+    fn row(&self, u: usize) -> &[u64] {
+        &self.rows[u * self.words_per_row..(u + 1) * self.words_per_row]
     }
 
-    /// Get the maximum degree of the graph
-    pub fn max_degree(&self) -> usize {
-        (0..self.n_vertices)
-            .map(|v| self.edges.get(&v).unwrap().len())
-            .max()
-            .unwrap_or(0)
+    fn clear_bit(&mut self, u: usize, v: usize) {
+        self.rows[u * self.words_per_row + v / 64] &= !(1 << (v % 64));
     }
+}
 
-    /// Check if the graph is the Petersen graph
-    fn is_petersen(&self) -> bool {
-        // The Petersen graph has exactly 10 vertices and 15 edges
-        if self.n_vertices != 10 || self.n_edges != 15 {
-            return false;
-        }
+impl DisjointPathWorkspace for BitsetAdjacency {
+    fn find_path(&self, s: usize, t: usize) -> Option<Vec<usize>> {
+        use std::collections::VecDeque;
 
-        // It's 3-regular (every vertex has degree 3)
-        if self.min_degree() != 3 || self.max_degree() != 3 {
-            return false;
-        }
+        let n = self.rows.len() / self.words_per_row.max(1);
+        let mut visited = vec![0u64; self.words_per_row];
+        let mut parent = vec![usize::MAX; n];
+        let mut queue = VecDeque::new();
 
-        // Additional check for girth (shortest cycle) = 5
-        // This is a simplified check - not comprehensive
-        let mut has_triangle = false;
-        let mut has_square = false;
+        visited[s / 64] |= 1 << (s % 64);
+        queue.push_back(s);
 
-        // Check for triangles (cycles of length 3)
-        for u in 0..self.n_vertices {
-            let neighbors_u: Vec<usize> = self.edges.get(&u).unwrap().iter().cloned().collect();
-            for &v in &neighbors_u {
-                for &w in &neighbors_u {
-                    if v != w && self.edges.get(&v).unwrap().contains(&w) {
-                        has_triangle = true;
-                        break;
-                    }
-                }
-                if has_triangle {
-                    break;
+        while let Some(u) = queue.pop_front() {
+            if u == t {
+                let mut path = vec![t];
+                let mut current = t;
+                while current != s {
+                    current = parent[current];
+                    path.push(current);
                 }
+
+                path.reverse();
+                return Some(path);
             }
-            if has_triangle {
-                break;
-            }
-        }
 
-        // Check for squares (cycles of length 4)
-        if !has_triangle {
-            'outer: for u in 0..self.n_vertices {
-                let neighbors_u: Vec<usize> = self.edges.get(&u).unwrap().iter().cloned().collect();
-                for &v in &neighbors_u {
-                    let neighbors_v: Vec<usize> =
-                        self.edges.get(&v).unwrap().iter().cloned().collect();
-                    for &w in &neighbors_v {
-                        if w != u {
-                            let neighbors_w: Vec<usize> =
-                                self.edges.get(&w).unwrap().iter().cloned().collect();
-                            for &x in &neighbors_w {
-                                if x != v && x != u && self.edges.get(&x).unwrap().contains(&u) {
-                                    has_square = true;
-                                    break 'outer;
-                                }
-                            }
-                        }
-                    }
+            for (w, (&word, seen)) in self.row(u).iter().zip(visited.iter_mut()).enumerate() {
+                let mut unvisited = word & !*seen;
+                *seen |= unvisited;
+                while unvisited != 0 {
+                    let v = w * 64 + unvisited.trailing_zeros() as usize;
+                    unvisited &= unvisited - 1;
+                    parent[v] = u;
+                    queue.push_back(v);
                 }
             }
         }
 
-        // Petersen graph has no triangles or squares
-        !has_triangle && !has_square
+        None
     }
 
-    /// Check if the graph is k-connected (wrapper function)
-    ///
-    /// # Arguments
-    ///
-    /// * `k` - The connectivity parameter to check
-    /// * `use_exact` - Whether to use the exact algorithm (slower but more accurate) or the approximation
-    ///
-    /// # Returns
-    ///
-    /// `true` if the graph is k-connected, `false` otherwise
-    pub fn is_k_connected(&self, k: usize, use_exact: bool) -> bool {
-        // Handle the complete graph case directly for robustness
-        if self.is_complete() {
-            return k <= self.n_vertices - 1;
-        }
+    fn remove_edge(&mut self, u: usize, v: usize) {
+        self.clear_bit(u, v);
+        self.clear_bit(v, u);
+    }
 
-        if use_exact {
-            self.is_k_connected_exact(k)
-        } else {
-            self.is_k_connected_approx(k)
+    fn isolate(&mut self, v: usize) {
+        for w in 0..self.words_per_row {
+            let mut neighbors = self.rows[v * self.words_per_row + w];
+            while neighbors != 0 {
+                let u = w * 64 + neighbors.trailing_zeros() as usize;
+                neighbors &= neighbors - 1;
+                self.clear_bit(u, v);
+            }
+            self.rows[v * self.words_per_row + w] = 0;
         }
     }
+}
 
-    /// Check if the graph is k-connected using an approximation algorithm
-    /// This is faster but may give incorrect results in some cases
-    pub fn is_k_connected_approx(&self, k: usize) -> bool {
-        // A graph with n vertices cannot be k-connected if k > n-1
-        if k > self.n_vertices - 1 {
-            return false;
-        }
+/// Hash set adjacency workspace, used for graphs too large for a bitset
+struct HashSetAdjacency<'a> {
+    graph: &'a Graph,
+    edges: Vec<HashSet<usize>>,
+}
 
-        // A necessary condition: minimum degree must be at least k
-        if self.min_degree() < k {
-            return false;
-        }
+impl DisjointPathWorkspace for HashSetAdjacency<'_> {
+    fn find_path(&self, s: usize, t: usize) -> Option<Vec<usize>> {
+        self.graph.find_path_in_subgraph(&self.edges, s, t)
+    }
 
-        // For k=1, just check if the graph is connected
-        if k == 1 {
-            return self.is_connected();
-        }
+    fn remove_edge(&mut self, u: usize, v: usize) {
+        self.edges[u].remove(&v);
+        self.edges[v].remove(&u);
+    }
 
-        // Complete graphs are (n-1)-connected but not n-connected
-        if self.is_complete() {
-            return k <= self.n_vertices - 1;
+    fn isolate(&mut self, v: usize) {
+        for u in std::mem::take(&mut self.edges[v]) {
+            self.edges[u].remove(&v);
         }
+    }
+}
 
-        // For cycle graphs: they are 2-connected but not 3-connected
-        if self.is_cycle() {
-            return k <= 2;
-        }
+/// Sum the squared sizes of adjacency sets, the first Zagreb index of an adjacency list
+fn squared_degree_sum(adjacency: &[HashSet<usize>]) -> usize {
+    adjacency.iter().map(|neighbors| neighbors.len() * neighbors.len()).sum()
+}
 
-        // For path graphs: they are only 1-connected
-        if self.is_path() {
-            return k <= 1;
+/// Greedily collect vertex-disjoint s-t paths: repeatedly take a shortest path and remove its
+/// internal vertices, stopping once `limit` paths are found
+/// The paths found are genuinely disjoint, so the count is a lower bound on the maximum
+fn count_greedy_disjoint_paths(
+    working: &mut impl DisjointPathWorkspace,
+    s: usize,
+    t: usize,
+    limit: usize,
+) -> usize {
+    let mut path_count = 0;
+
+    while path_count < limit {
+        let Some(path) = working.find_path(s, t) else {
+            break;
+        };
+        path_count += 1;
+
+        // Remove internal vertices of the path; each round removes at least one vertex
+        for &v in &path[1..path.len() - 1] {
+            working.isolate(v);
         }
+    }
 
-        // For star graphs: they are only 1-connected
-        if self.is_star() {
-            return k <= 1;
-        }
+    path_count
+}
 
-        // Check if the graph is "dense enough" to be potentially k-connected
-        // A graph with n vertices and at least (n-1)k/2 + 1 edges is often k-connected
-        let density_threshold = (self.n_vertices - 1) * k / 2 + 1;
+/// Residual network for unit-capacity max-flow computations with Dinic's algorithm
+/// Arcs are stored in pairs, so the reverse of arc `a` is `a ^ 1`
+struct FlowNetwork {
+    /// Outgoing arc indices of each node
+    adjacency: Vec<Vec<usize>>,
+    /// Target node of each arc
+    head: Vec<usize>,
+    /// Remaining capacity of each arc
+    capacity: Vec<u32>,
+}
 
-        if self.n_edges >= density_threshold {
-            return true;
+impl FlowNetwork {
+    fn new(nodes: usize) -> Self {
+        FlowNetwork {
+            adjacency: vec![Vec::new(); nodes],
+            head: Vec::new(),
+            capacity: Vec::new(),
         }
+    }
 
-        // For graphs that don't meet the density threshold, we'll use another heuristic
-        // based on the average degree and the Zagreb index
-        let avg_degree = 2.0 * self.n_edges as f64 / self.n_vertices as f64;
-        let z1 = self.first_zagreb_index();
-
-        // Higher Zagreb index relative to number of edges suggests better connectivity
-        z1 as f64 / self.n_edges as f64 >= k as f64 * avg_degree
+    /// Add an arc from `from` to `to` together with its zero-capacity reverse arc
+    fn add_arc(&mut self, from: usize, to: usize, capacity: u32) {
+        let arc = self.head.len();
+        self.head.extend([to, from]);
+        self.capacity.extend([capacity, 0]);
+        self.adjacency[from].push(arc);
+        self.adjacency[to].push(arc + 1);
     }
 
-    /// Check if the graph is k-connected using an exact algorithm based on Menger's theorem
-    /// This is slower but gives correct results for all graphs
-    pub fn is_k_connected_exact(&self, k: usize) -> bool {
-        // A graph with n vertices cannot be k-connected if k > n-1
-        if k > self.n_vertices - 1 {
-            return false;
+    /// Mark the nodes reachable from `source` along arcs with remaining capacity
+    /// After `max_flow` these are the source side of a minimum cut
+    fn reachable_from(&self, source: usize) -> Vec<bool> {
+        let mut reachable = vec![false; self.adjacency.len()];
+        let mut stack = vec![source];
+        reachable[source] = true;
+
+        while let Some(u) = stack.pop() {
+            for &arc in &self.adjacency[u] {
+                let v = self.head[arc];
+                if self.capacity[arc] > 0 && !reachable[v] {
+                    reachable[v] = true;
+                    stack.push(v);
+                }
+            }
         }
 
-        // A necessary condition: minimum degree must be at least k
-        if self.min_degree() < k {
-            return false;
-        }
+        reachable
+    }
 
-        // Special case for complete graphs - they are (n-1)-connected but not n-connected
-        if self.is_complete() {
-            return k <= self.n_vertices - 1;
-        }
+    /// Compute the maximum flow from source to sink, consuming the capacities
+    /// Each phase builds a BFS level graph and saturates it with a blocking flow found by
+    /// iterative DFS, so deep networks cannot overflow the stack
+    fn max_flow(&mut self, source: usize, sink: usize) -> usize {
+        use std::collections::VecDeque;
+
+        let nodes = self.adjacency.len();
+        let mut flow = 0;
+
+        loop {
+            let mut level = vec![usize::MAX; nodes];
+            let mut queue = VecDeque::from([source]);
+            level[source] = 0;
+            while let Some(u) = queue.pop_front() {
+                for &arc in &self.adjacency[u] {
+                    let v = self.head[arc];
+                    if self.capacity[arc] > 0 && level[v] == usize::MAX {
+                        level[v] = level[u] + 1;
+                        queue.push_back(v);
+                    }
+                }
+            }
 
-        // For k=1, just check if the graph is connected (optimization)
-        if k == 1 {
-            return self.is_connected();
-        }
+            if level[sink] == usize::MAX {
+                return flow;
+            }
 
-        // Implementation of the exact algorithm using flow networks
-        self.mengers_theorem_check(k)
-    }
+            // Index of the next arc to try at each node; dead-end arcs are skipped for good
+            let mut next = vec![0; nodes];
 
-    /// Implements an exact check for k-connectivity using Menger's theorem
-    /// Menger's theorem states that a graph is k-vertex-connected if and only if
-    /// any pair of vertices is connected by at least k vertex-disjoint paths.
-    fn mengers_theorem_check(&self, k: usize) -> bool {
-        // Special cases
-        if self.n_vertices <= k {
-            return false; // Can't be k-connected with only k vertices
-        }
+            'blocking: loop {
+                let mut path: Vec<usize> = Vec::new();
+                let mut u = source;
 
-        // A necessary condition: minimum degree must be at least k
-        if self.min_degree() < k {
-            return false;
-        }
+                while u != sink {
+                    let advance = self.adjacency[u][next[u]..].iter().position(|&arc| {
+                        self.capacity[arc] > 0 && level[self.head[arc]] == level[u] + 1
+                    });
 
-        // For k=1, just check if the graph is connected (optimization)
-        if k == 1 {
-            return self.is_connected();
-        }
+                    match advance {
+                        Some(offset) => {
+                            next[u] += offset;
+                            let arc = self.adjacency[u][next[u]];
+                            path.push(arc);
+                            u = self.head[arc];
+                        }
+                        None => {
+                            next[u] = self.adjacency[u].len();
+                            // Retreat from the dead end, or stop once the source is stuck
+                            let Some(arc) = path.pop() else {
+                                break 'blocking;
+                            };
+                            u = self.head[arc ^ 1];
+                            next[u] += 1;
+                        }
+                    }
+                }
 
-        // Special cases for common graph types
-        if self.is_cycle() {
-            return k <= 2; // Cycle graphs are 2-connected but not 3-connected
+                for &arc in &path {
+                    self.capacity[arc] -= 1;
+                    self.capacity[arc ^ 1] += 1;
+                }
+                flow += 1;
+            }
         }
+    }
+}
 
-        if self.is_complete() {
-            return k <= self.n_vertices - 1; // Complete graphs are (n-1)-connected
-        }
+/// Summary of the three theorems from the paper evaluated on a single graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct TheoremReport {
+    /// First Zagreb index of the graph
+    pub zagreb_index: usize,
+    /// Theorem 1 threshold for Hamiltonicity (k = 2)
+    pub hamiltonian_threshold: usize,
+    /// Whether the Zagreb index reaches the Theorem 1 threshold
+    pub hamiltonian_threshold_met: bool,
+    /// Theorem 2 threshold for traceability (k = 1)
+    pub traceable_threshold: usize,
+    /// Whether the Zagreb index reaches the Theorem 2 threshold
+    pub traceable_threshold_met: bool,
+    /// Theorem 3 upper bound on the Zagreb index
+    pub zagreb_upper_bound: f64,
+}
 
-        // For each pair of distinct vertices, check if they have at least k vertex-disjoint paths
-        for s in 0..self.n_vertices {
-            for t in (s + 1)..self.n_vertices {
-                let disjoint_paths = self.find_vertex_disjoint_paths(s, t);
-                if disjoint_paths < k {
-                    return false;
+/// Solve the dense linear system `a · x = b` by Gaussian elimination with partial pivoting
+/// Returns None if the matrix is singular
+fn solve_linear_system(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let factor = row[col] / pivot_row[col];
+            if factor != 0.0 {
+                for (entry, &pivot_entry) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                    *entry -= factor * pivot_entry;
                 }
+                b[col + 1 + offset] -= factor * b[col];
             }
         }
+    }
 
-        true
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = ((row + 1)..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
     }
 
-    /// Check if the graph is connected (1-connected)
-    fn is_connected(&self) -> bool {
-        if self.n_vertices == 0 {
-            return true;
+    Some(x)
+}
+
+/// Compute the eigenvalues of a symmetric matrix with the cyclic Jacobi method
+/// Returns the eigenvalues in ascending order
+fn symmetric_eigenvalues(mut a: Vec<Vec<f64>>) -> Vec<f64> {
+    let n = a.len();
+    let norm: f64 = a.iter().flatten().map(|x| x * x).sum();
+
+    for _sweep in 0..100 {
+        let off_diagonal: f64 = (0..n)
+            .flat_map(|p| ((p + 1)..n).map(move |q| (p, q)))
+            .map(|(p, q)| a[p][q] * a[p][q])
+            .sum();
+        if off_diagonal <= 1e-24 * norm {
+            break;
         }
 
-        use std::collections::{HashSet, VecDeque};
+        for p in 0..n {
+            for q in (p + 1)..n {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
 
-        let mut visited = HashSet::new();
-        let mut queue = VecDeque::new();
+                // Rotation that zeroes a[p][q]
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
 
-        // Start BFS from vertex 0
-        visited.insert(0);
-        queue.push_back(0);
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
 
-        while let Some(v) = queue.pop_front() {
-            for &neighbor in self.edges.get(&v).unwrap() {
-                if !visited.contains(&neighbor) {
-                    visited.insert(neighbor);
-                    queue.push_back(neighbor);
+                let (upper, lower) = a.split_at_mut(q);
+                for (apk, aqk) in upper[p].iter_mut().zip(lower[0].iter_mut()) {
+                    let (x, y) = (*apk, *aqk);
+                    *apk = c * x - s * y;
+                    *aqk = s * x + c * y;
                 }
             }
         }
-
-        // If we visited all vertices, the graph is connected
-        visited.len() == self.n_vertices
     }
 
-    /// Find the maximum number of vertex-disjoint paths between vertices s and t
-    /// This uses a more comprehensive algorithm for both adjacent and non-adjacent vertices
-    fn find_vertex_disjoint_paths(&self, s: usize, t: usize) -> usize {
-        use std::collections::{HashMap, HashSet};
+    let mut eigenvalues: Vec<f64> = (0..n).map(|i| a[i][i]).collect();
+    eigenvalues.sort_by(f64::total_cmp);
+    eigenvalues
+}
 
-        // Handle special cases for common graph types
-        // Complete graph with n vertices has n-1 vertex-disjoint paths between any two vertices
-        if self.is_complete() {
-            return self.n_vertices - 1;
-        }
+/// One level of the Louvain hierarchy: a weighted graph whose nodes are the communities
+/// found on the level below
+struct LouvainLevel {
+    /// Weighted neighbors of each node, excluding the node itself
+    neighbors: Vec<Vec<(usize, f64)>>,
+    /// Weight of the edges inside each node, i.e. inside the merged community
+    self_loops: Vec<f64>,
+}
 
-        // For cycle graphs, there are always 2 vertex-disjoint paths between any pair of vertices
-        if self.is_cycle() {
-            return 2;
-        }
+impl LouvainLevel {
+    /// Weighted degree of node i; internal edges count at both of their endpoints
+    fn degree(&self, i: usize) -> f64 {
+        self.neighbors[i].iter().map(|&(_, w)| w).sum::<f64>() + 2.0 * self.self_loops[i]
+    }
 
-        // Path graphs have only 1 vertex-disjoint path between end vertices
-        if self.is_path()
-            && ((s == 0 && t == self.n_vertices - 1) || (t == 0 && s == self.n_vertices - 1))
-        {
-            return 1;
-        }
+    /// Move nodes between neighboring communities while modularity strictly improves
+    ///
+    /// Nodes are visited in index order, repeatedly, until a full pass moves nothing.
+    /// Returns each node's community, numbered 0.. in order of first appearance, the number of
+    /// communities, and whether any node moved.
+    fn local_moves(&self, total_weight: f64) -> (Vec<usize>, usize, bool) {
+        let n = self.neighbors.len();
+        let degrees: Vec<f64> = (0..n).map(|i| self.degree(i)).collect();
+        let mut community: Vec<usize> = (0..n).collect();
+        let mut community_degree = degrees.clone();
+        let mut weight_to = vec![0.0; n];
+        let mut touched = Vec::new();
+        let mut any_moved = false;
+
+        loop {
+            let mut moved = false;
+            for i in 0..n {
+                let current = community[i];
+                community_degree[current] -= degrees[i];
+
+                for &(j, w) in &self.neighbors[i] {
+                    let c = community[j];
+                    if weight_to[c] == 0.0 {
+                        touched.push(c);
+                    }
+                    weight_to[c] += w;
+                }
 
-        // For adjacent vertices, we need to check both the direct edge and potential paths that don't use it
-        if self.edges.get(&s).unwrap().contains(&t) {
-            // Get the neighbors of both vertices
-            let s_neighbors: HashSet<_> = self.edges.get(&s).unwrap().iter().cloned().collect();
-            let t_neighbors: HashSet<_> = self.edges.get(&t).unwrap().iter().cloned().collect();
+                // Modularity gain of joining c, up to a factor common to every c. Only a strict
+                // improvement moves the node, so ties keep it where it is and passes terminate.
+                let gain = |c: usize| {
+                    weight_to[c] - community_degree[c] * degrees[i] / (2.0 * total_weight)
+                };
+                let mut best = current;
+                let mut best_gain = gain(current);
+                for &c in &touched {
+                    let g = gain(c);
+                    if g > best_gain + 1e-12 {
+                        best = c;
+                        best_gain = g;
+                    }
+                }
 
-            // Find common neighbors (excluding s and t themselves)
-            let mut common = s_neighbors
-                .intersection(&t_neighbors)
-                .cloned()
-                .collect::<HashSet<_>>();
-            common.remove(&s);
-            common.remove(&t);
+                for c in touched.drain(..) {
+                    weight_to[c] = 0.0;
+                }
+                community_degree[best] += degrees[i];
+                if best != current {
+                    community[i] = best;
+                    moved = true;
+                    any_moved = true;
+                }
+            }
+
+            if !moved {
+                break;
+            }
+        }
+
+        let mut renumber = vec![usize::MAX; n];
+        let mut count = 0;
+        for c in community.iter_mut() {
+            if renumber[*c] == usize::MAX {
+                renumber[*c] = count;
+                count += 1;
+            }
+            *c = renumber[*c];
+        }
 
-            // For adjacent vertices, we want to find the maximum number of vertex-disjoint paths
-            // We know there's at least 1 path (the direct edge), but there might be more
+        (community, count, any_moved)
+    }
 
-            // Create a modified graph without the direct edge to find additional paths
-            let mut modified_edges = HashMap::new();
-            for (vertex, neighbors) in &self.edges {
-                let mut new_neighbors = neighbors.clone();
-                if *vertex == s {
-                    new_neighbors.remove(&t);
-                } else if *vertex == t {
-                    new_neighbors.remove(&s);
+    /// Collapse each community into a single node of the next level
+    fn aggregate(&self, community: &[usize], count: usize) -> LouvainLevel {
+        let mut weights: Vec<HashMap<usize, f64>> = vec![HashMap::new(); count];
+        let mut self_loops = vec![0.0; count];
+
+        for (i, neighbors) in self.neighbors.iter().enumerate() {
+            let ci = community[i];
+            self_loops[ci] += self.self_loops[i];
+            for &(j, w) in neighbors {
+                let cj = community[j];
+                if ci != cj {
+                    *weights[ci].entry(cj).or_insert(0.0) += w;
+                } else if i < j {
+                    self_loops[ci] += w;
                 }
-                modified_edges.insert(*vertex, new_neighbors);
             }
+        }
 
-            // Find paths in the modified graph (without the direct edge)
-            let mut path_count = 0;
-            let mut working_edges = modified_edges.clone();
+        let neighbors = weights
+            .into_iter()
+            .map(|targets| {
+                let mut sorted: Vec<(usize, f64)> = targets.into_iter().collect();
+                sorted.sort_unstable_by_key(|&(j, _)| j);
+                sorted
+            })
+            .collect();
 
-            // Maximum possible paths is bounded by min degree
-            let max_possible_paths = std::cmp::min(
-                self.edges.get(&s).unwrap().len(),
-                self.edges.get(&t).unwrap().len(),
-            );
+        LouvainLevel { neighbors, self_loops }
+    }
+}
 
-            // Safety limit to prevent infinite loops
-            let max_attempts = 100;
-            let mut attempts = 0;
+impl PartialEq for Graph {
+    /// Two graphs are equal when they have the same vertex count and the same edges with the
+    /// same weights, regardless of the order in which the edges were added
+    fn eq(&self, other: &Self) -> bool {
+        self.n_vertices == other.n_vertices
+            && self.n_edges == other.n_edges
+            && self.edges == other.edges
+            && self.weights == other.weights
+    }
+}
 
-            // Find vertex-disjoint paths in the modified graph
-            while let Some(path) = self.find_path_in_subgraph(&working_edges, s, t) {
-                path_count += 1;
+impl Eq for Graph {}
 
-                // If we've found enough paths or reached attempt limit, stop
-                if path_count >= max_possible_paths - 1 || attempts >= max_attempts {
-                    break;
-                }
+impl fmt::Debug for Graph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Graph {{")?;
+        writeln!(f, "  vertices: {},", self.n_vertices)?;
+        writeln!(f, "  edges: {},", self.n_edges)?;
+        writeln!(f, "  adjacency list: {{")?;
+        for v in 0..self.n_vertices {
+            let neighbors: Vec<usize> = self.edges[v].iter().cloned().collect();
+            writeln!(f, "    {}: {:?},", v, neighbors)?;
+        }
+        writeln!(f, "  }}")?;
+        write!(f, "}}")
+    }
+}
 
-                attempts += 1;
+impl fmt::Display for Graph {
+    /// One-line summary for logging, e.g. `Graph(n=10, m=15, M1=90)`
+    /// Use `{:?}` for the full adjacency list
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Graph(n={}, m={}, M1={})",
+            self.n_vertices,
+            self.n_edges,
+            self.first_zagreb_index()
+        )
+    }
+}
 
-                // Remove internal vertices of the path
-                for &v in path.iter().skip(1).take(path.len() - 2) {
-                    // Get all neighbors
-                    if let Some(neighbors) = working_edges.get(&v) {
-                        let neighbors_copy: Vec<usize> = neighbors.iter().cloned().collect();
+impl Graph {
+    /// Create a new empty graph with n vertices
+    pub fn new(n: usize) -> Self {
+        Graph {
+            edges: vec![HashSet::new(); n],
+            n_vertices: n,
+            n_edges: 0,
+            weights: HashMap::new(),
+            zagreb_index: 0,
+            degree_stats: OnceLock::new(),
+        }
+    }
 
-                        // Remove all edges connected to this vertex
-                        for &neighbor in &neighbors_copy {
-                            if let Some(edges) = working_edges.get_mut(&v) {
-                                edges.remove(&neighbor);
-                            }
-                            if let Some(edges) = working_edges.get_mut(&neighbor) {
-                                edges.remove(&v);
-                            }
-                        }
-                    }
+    /// Enumerate every labeled simple graph on n vertices
+    ///
+    /// There are 2^(n(n-1)/2) such graphs, so this is only practical for n ≤ 6.
+    /// It is intended for exhaustive testing against brute-force results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if n(n-1)/2 ≥ 64, as the edge subsets could not be enumerated anyway
+    pub fn all_graphs(n: usize) -> impl Iterator<Item = Graph> {
+        let pairs: Vec<(usize, usize)> = (0..n)
+            .flat_map(|u| ((u + 1)..n).map(move |v| (u, v)))
+            .collect();
+        assert!(pairs.len() < 64, "Too many vertices to enumerate all graphs");
+
+        (0..(1u64 << pairs.len())).map(move |mask| {
+            let mut graph = Graph::new(n);
+            for (i, &(u, v)) in pairs.iter().enumerate() {
+                if mask & (1 << i) != 0 {
+                    graph.add_edge(u, v).unwrap();
                 }
             }
+            graph
+        })
+    }
 
-            // Total paths = direct edge + paths found in modified graph
-            return 1 + path_count;
+    /// Create a `rows × cols` grid (2D lattice) graph
+    /// The cell in row r and column c is vertex `r * cols + c`, adjacent to its horizontal
+    /// and vertical neighbors
+    pub fn grid(rows: usize, cols: usize) -> Graph {
+        let mut graph = Graph::new(rows * cols);
+
+        for r in 0..rows {
+            for c in 0..cols {
+                let v = r * cols + c;
+                if c + 1 < cols {
+                    graph.add_edge(v, v + 1).unwrap();
+                }
+                if r + 1 < rows {
+                    graph.add_edge(v, v + cols).unwrap();
+                }
+            }
         }
 
-        // For non-adjacent vertices, use the standard path-finding algorithm
-        // Create a working copy of the graph's adjacency structure
-        let mut working_edges = HashMap::new();
-        for (vertex, neighbors) in &self.edges {
-            working_edges.insert(*vertex, neighbors.clone());
+        graph
+    }
+
+    /// Create the path graph P_n, with edges `i – i+1`
+    pub fn path(n: usize) -> Graph {
+        let mut graph = Graph::new(n);
+        for v in 1..n {
+            graph.add_edge(v - 1, v).unwrap();
         }
 
-        let mut path_count = 0;
+        graph
+    }
+
+    /// Create the cycle graph C_n, with edges `i – (i+1) mod n`
+    ///
+    /// # Panics
+    ///
+    /// Panics if n < 3, since shorter cycles would need loops or parallel edges
+    pub fn cycle(n: usize) -> Graph {
+        assert!(n >= 3, "A cycle needs at least 3 vertices");
 
-        // Maximum possible paths is bounded by min degree
-        let max_possible_paths = std::cmp::min(
-            self.edges.get(&s).unwrap().len(),
-            self.edges.get(&t).unwrap().len(),
-        );
+        let mut graph = Graph::path(n);
+        graph.add_edge(n - 1, 0).unwrap();
+        graph
+    }
 
-        // Safety limit to prevent infinite loops
-        let max_attempts = 100;
-        let mut attempts = 0;
+    /// Create the star graph with n vertices
+    /// Vertex 0 is the center, adjacent to every leaf `1..n`
+    pub fn star(n: usize) -> Graph {
+        let mut graph = Graph::new(n);
+        for v in 1..n {
+            graph.add_edge(0, v).unwrap();
+        }
 
-        // Find vertex-disjoint paths
-        while let Some(path) = self.find_path_in_subgraph(&working_edges, s, t) {
-            path_count += 1;
+        graph
+    }
 
-            // If we've found enough paths or reached attempt limit, stop
-            if path_count >= max_possible_paths || attempts >= max_attempts {
-                break;
+    /// Create the complete graph K_n
+    pub fn complete(n: usize) -> Graph {
+        let mut graph = Graph::new(n);
+        for u in 0..n {
+            for v in (u + 1)..n {
+                graph.add_edge(u, v).unwrap();
             }
+        }
+
+        graph
+    }
+
+    /// Create the Petersen graph
+    /// Vertices 0–4 form the outer pentagon, 5–9 the inner pentagram, and spoke i joins i to i + 5
+    pub fn petersen() -> Graph {
+        let mut graph = Graph::new(10);
+        for i in 0..5 {
+            graph.add_edge(i, (i + 1) % 5).unwrap();
+            graph.add_edge(i, i + 5).unwrap();
+            graph.add_edge(i + 5, (i + 2) % 5 + 5).unwrap();
+        }
 
-            attempts += 1;
+        graph
+    }
 
-            // Remove internal vertices of the path
-            for &v in path.iter().skip(1).take(path.len() - 2) {
-                // Get all neighbors
-                if let Some(neighbors) = working_edges.get(&v) {
-                    let neighbors_copy: Vec<usize> = neighbors.iter().cloned().collect();
+    /// Create a wheel graph with n vertices
+    /// Vertex 0 is the hub, adjacent to every rim vertex `1..n`, and the rim vertices form a cycle
+    ///
+    /// # Panics
+    ///
+    /// Panics if n < 4, since the rim must be a cycle of at least 3 vertices
+    pub fn wheel(n: usize) -> Graph {
+        assert!(n >= 4, "A wheel needs at least 4 vertices");
+
+        let mut graph = Graph::new(n);
+        for v in 1..n {
+            graph.add_edge(0, v).unwrap();
+            graph.add_edge(v, v % (n - 1) + 1).unwrap();
+        }
 
-                    // Remove all edges connected to this vertex
-                    for &neighbor in &neighbors_copy {
-                        if let Some(edges) = working_edges.get_mut(&v) {
-                            edges.remove(&neighbor);
-                        }
-                        if let Some(edges) = working_edges.get_mut(&neighbor) {
-                            edges.remove(&v);
-                        }
-                    }
+        graph
+    }
+
+    /// Generate an Erdős–Rényi random graph G(n, p)
+    /// Each of the n(n-1)/2 possible edges is included independently with probability p
+    ///
+    /// # Panics
+    ///
+    /// Panics if p is not within `0.0..=1.0`
+    pub fn erdos_renyi(n: usize, p: f64, rng: &mut impl Rng) -> Graph {
+        assert!((0.0..=1.0).contains(&p), "Edge probability must be between 0 and 1");
+
+        let mut graph = Graph::new(n);
+        for u in 0..n {
+            for v in (u + 1)..n {
+                if rng.random_bool(p) {
+                    graph.add_edge(u, v).unwrap();
                 }
             }
         }
 
-        path_count
+        graph
     }
 
-    /// Helper function to find a path in a subgraph represented by the given edges
-    fn find_path_in_subgraph(
-        &self,
-        edges: &HashMap<usize, HashSet<usize>>,
-        s: usize,
-        t: usize,
-    ) -> Option<Vec<usize>> {
-        use std::collections::{HashMap, HashSet, VecDeque};
-
-        let mut visited = HashSet::new();
-        let mut queue = VecDeque::new();
-        let mut parent = HashMap::new();
+    /// Build a bipartite graph from a biadjacency matrix
+    ///
+    /// The graph has `rows + cols` vertices: left vertex i is vertex i and right vertex j is
+    /// vertex `rows + j`. An entry `matrix[i][j] == 1` adds an edge between them, 0 adds nothing.
+    pub fn from_biadjacency(rows: usize, cols: usize, matrix: &[Vec<u8>]) -> Result<Graph, GraphError> {
+        if matrix.len() != rows {
+            return Err(GraphError::InvalidInput(format!(
+                "expected {} rows, found {}",
+                rows,
+                matrix.len()
+            )));
+        }
 
-        visited.insert(s);
-        queue.push_back(s);
+        let mut graph = Graph::new(rows + cols);
 
-        while let Some(u) = queue.pop_front() {
-            if u == t {
-                // Reconstruct the path
-                let mut path = Vec::new();
-                let mut current = t;
+        for (i, row) in matrix.iter().enumerate() {
+            if row.len() != cols {
+                return Err(GraphError::InvalidInput(format!(
+                    "row {} has {} entries, expected {}",
+                    i,
+                    row.len(),
+                    cols
+                )));
+            }
 
-                path.push(current);
-                while current != s {
-                    current = *parent.get(&current).unwrap();
-                    path.push(current);
+            for (j, &entry) in row.iter().enumerate() {
+                match entry {
+                    0 => {}
+                    1 => graph.add_edge(i, rows + j).unwrap(),
+                    _ => {
+                        return Err(GraphError::InvalidInput(format!(
+                            "entry [{}][{}] must be 0 or 1, found {}",
+                            i, j, entry
+                        )))
+                    }
                 }
+            }
+        }
 
-                path.reverse();
-                return Some(path);
+        Ok(graph)
+    }
+
+    /// Build a graph from a symmetric 0/1 adjacency matrix
+    /// Ragged rows, nonzero diagonal entries (self-loops) and asymmetric entries are rejected
+    pub fn from_adjacency_matrix(matrix: &[Vec<u8>]) -> Result<Graph, GraphError> {
+        let n = matrix.len();
+        let mut graph = Graph::new(n);
+
+        for (i, row) in matrix.iter().enumerate() {
+            if row.len() != n {
+                return Err(GraphError::InvalidInput(format!(
+                    "row {} has {} entries, expected {}",
+                    i,
+                    row.len(),
+                    n
+                )));
+            }
+        }
+
+        for (i, row) in matrix.iter().enumerate() {
+            if row[i] != 0 {
+                return Err(GraphError::SelfLoop(i));
             }
 
-            for &v in edges.get(&u).unwrap() {
-                if !visited.contains(&v) {
-                    visited.insert(v);
-                    parent.insert(v, u);
-                    queue.push_back(v);
+            for (j, &entry) in row.iter().enumerate().skip(i + 1) {
+                if entry != matrix[j][i] {
+                    return Err(GraphError::InvalidInput(format!(
+                        "matrix is not symmetric at [{}][{}]",
+                        i, j
+                    )));
+                }
+
+                match entry {
+                    0 => {}
+                    1 => graph.add_edge(i, j).unwrap(),
+                    entry => {
+                        return Err(GraphError::InvalidInput(format!(
+                            "entry [{}][{}] must be 0 or 1, found {}",
+                            i, j, entry
+                        )))
+                    }
                 }
             }
         }
 
-        None
+        Ok(graph)
     }
 
-    /// Find a path between vertices s and t using breadth-first search
-    /// Returns None if no path exists
-    fn find_path(&self, s: usize, t: usize) -> Option<Vec<usize>> {
-        self.find_path_in_subgraph(&self.edges, s, t)
-    }
+    /// Build a graph on n vertices from `(u, v)` edge pairs in a single pass
+    /// Every edge is validated; repeated edges are added once
+    pub fn from_edges(
+        n: usize,
+        edges: impl IntoIterator<Item = (usize, usize)>,
+    ) -> Result<Graph, GraphError> {
+        let mut graph = Graph::new(n);
+        for (u, v) in edges {
+            graph.try_add_edge(u, v)?;
+        }
 
-    /// Check if there is a path between vertices s and t
-    fn is_path_between(&self, s: usize, t: usize) -> bool {
-        self.find_path(s, t).is_some()
+        Ok(graph)
     }
 
-    /// Calculate independence number (approximate)
-    /// Finding the exact independence number is NP-hard, so this is a greedy approximation
-    pub fn independence_number_approx(&self) -> usize {
-        let mut independent_set = HashSet::new();
-        let mut remaining_vertices: HashSet<usize> = (0..self.n_vertices).collect();
+    /// Parse a graph on n vertices from a whitespace-separated list of `u v` pairs
+    /// Blank lines are skipped and everything after a `#` on a line is treated as a comment
+    pub fn from_edge_list(n: usize, text: &str) -> Result<Graph, GraphError> {
+        let mut tokens = Vec::new();
+        for line in text.lines() {
+            let content = line.split('#').next().unwrap_or("");
+            for token in content.split_whitespace() {
+                let vertex = token.parse::<usize>().map_err(|_| {
+                    GraphError::InvalidInput(format!("'{}' is not a vertex index", token))
+                })?;
+                tokens.push(vertex);
+            }
+        }
 
-        while !remaining_vertices.is_empty() {
-            // Select vertex with minimum degree in the remaining graph
-            let min_degree_vertex = *remaining_vertices
-                .iter()
-                .min_by_key(|&&v| {
-                    self.edges
-                        .get(&v)
-                        .unwrap()
-                        .iter()
-                        .filter(|&&u| remaining_vertices.contains(&u))
-                        .count()
-                })
-                .unwrap();
+        if tokens.len() % 2 != 0 {
+            return Err(GraphError::InvalidInput(
+                "edge list contains an unpaired vertex".to_string(),
+            ));
+        }
 
-            // Add it to independent set
-            independent_set.insert(min_degree_vertex);
+        Graph::from_edges(n, tokens.chunks(2).map(|pair| (pair[0], pair[1])))
+    }
 
-            // Remove it and its neighbors from consideration
-            remaining_vertices.remove(&min_degree_vertex);
-            for &neighbor in self.edges.get(&min_degree_vertex).unwrap() {
-                remaining_vertices.remove(&neighbor);
+    /// Parse a graph on n vertices from CSV with one `source,target` row per edge
+    /// A header row is skipped if neither field of the first non-blank row is a vertex index;
+    /// a first row with only one numeric field is an error like any other malformed row.
+    /// Whitespace and double quotes around a field are ignored, and blank lines are skipped.
+    pub fn from_edge_csv(n: usize, csv: &str) -> Result<Graph, GraphError> {
+        let mut graph = Graph::new(n);
+
+        let rows = csv.lines().map(str::trim).filter(|line| !line.is_empty());
+        for (i, row) in rows.enumerate() {
+            let fields: Vec<&str> = row
+                .split(',')
+                .map(|field| field.trim().trim_matches('"').trim())
+                .collect();
+            if fields.len() != 2 {
+                return Err(GraphError::InvalidInput(format!(
+                    "expected 2 fields in CSV row '{}'",
+                    row
+                )));
+            }
+
+            match (fields[0].parse::<usize>(), fields[1].parse::<usize>()) {
+                (Ok(u), Ok(v)) => graph.try_add_edge(u, v)?,
+                (Err(_), Err(_)) if i == 0 => continue, // header row
+                _ => {
+                    return Err(GraphError::InvalidInput(format!(
+                        "CSV row '{}' is not a pair of vertex indices",
+                        row
+                    )))
+                }
             }
         }
 
-        independent_set.len()
+        Ok(graph)
     }
 
-    /// Check if the graph is likely Hamiltonian using Theorem 1 from the paper and known graph properties
-    ///
-    /// # Arguments
-    ///
-    /// * `use_exact_connectivity` - Whether to use exact connectivity checking (slower but more accurate)
-    pub fn is_likely_hamiltonian(&self, use_exact_connectivity: bool) -> bool {
-        // We need at least 3 vertices for a Hamiltonian cycle
-        if self.n_vertices < 3 {
-            return false;
+    /// Add an edge between vertices u and v, reporting failures as a `GraphError`
+    fn try_add_edge(&mut self, u: usize, v: usize) -> Result<(), GraphError> {
+        if u >= self.n_vertices {
+            return Err(GraphError::VertexOutOfBounds(u));
         }
-
-        // Known case: Complete graphs with n ≥ 3 are always Hamiltonian
-        if self.is_complete() {
-            return true;
+        if v >= self.n_vertices {
+            return Err(GraphError::VertexOutOfBounds(v));
         }
-
-        // Known case: Cycle graphs are Hamiltonian by definition
-        if self.is_cycle() {
-            return true;
+        if u == v {
+            return Err(GraphError::SelfLoop(u));
         }
 
-        // Special case: Stars with n > 3 are not Hamiltonian
-        if self.is_star() && self.n_vertices > 3 {
-            return false;
-        }
+        self.add_edge(u, v).map_err(|e| GraphError::InvalidInput(e.to_string()))
+    }
 
-        // Special case: The Petersen graph is known to be non-Hamiltonian
-        if self.is_petersen() {
-            return false;
+    /// Add an edge between vertices u and v
+    pub fn add_edge(&mut self, u: usize, v: usize) -> Result<(), &'static str> {
+        if u >= self.n_vertices || v >= self.n_vertices {
+            return Err("Vertex index out of bounds");
         }
 
-        // Check k-connectivity first (k ≥ 2)
-        let k = 2;
-        if !self.is_k_connected(k, use_exact_connectivity) {
-            return false;
+        if u == v {
+            return Err("Self-loops are not allowed");
         }
 
-        // Dirac's theorem: If minimum degree ≥ n/2, the graph is Hamiltonian
-        if self.min_degree() >= self.n_vertices / 2 {
-            return true;
+        // Check if the edge already exists
+        if self.edges[u].contains(&v) {
+            return Ok(()); // Edge already exists
         }
 
-        let delta = self.min_degree();
-        let delta_max = self.max_degree();
-        let n = self.n_vertices;
-        let e = self.n_edges;
-        let z1 = self.first_zagreb_index();
-
-        // Apply Theorem 1 from the paper
-        let part1 = (n - k - 1) * delta_max * delta_max;
-        let part2 = (e * e) / (k + 1);
-        let part3 = ((n - k - 1) as f64).sqrt() - (delta as f64).sqrt();
-        let part3_squared = part3 * part3;
-        let threshold = part1 + part2 + (part3_squared * e as f64) as usize;
+        // Add the edge in both directions (undirected graph)
+        self.insert_edge_unchecked(u, v);
 
-        z1 >= threshold
+        Ok(())
     }
 
-    /// Check if the graph is likely traceable using Theorem 2 from the paper and known graph properties
+    /// Insert a new edge between distinct in-bounds vertices that are not yet adjacent
     ///
-    /// # Arguments
+    /// Raising a degree from d to d + 1 adds 2d + 1 to its square, so the first Zagreb index
+    /// grows by 2·d(u) + 1 + 2·d(v) + 1, using the degrees before the insertion.
+    fn insert_edge_unchecked(&mut self, u: usize, v: usize) {
+        debug_assert!(u != v && !self.edges[u].contains(&v));
+        self.zagreb_index += 2 * (self.edges[u].len() + self.edges[v].len()) + 2;
+        self.edges[u].insert(v);
+        self.edges[v].insert(u);
+        self.n_edges += 1;
+        self.degree_stats.take();
+    }
+
+    /// Remove vertex v and all of its incident edges
     ///
-    /// * `use_exact_connectivity` - Whether to use exact connectivity checking (slower but more accurate)
-    pub fn is_likely_traceable(&self, use_exact_connectivity: bool) -> bool {
-        // We need at least 2 vertices for a Hamiltonian path
-        if self.n_vertices < 2 {
-            return false;
+    /// The remaining vertices are re-indexed to stay contiguous: every vertex u > v becomes
+    /// u - 1, while vertices below v keep their index. Any vertex indices held outside the
+    /// graph that are greater than v are stale after this call.
+    pub fn remove_vertex(&mut self, v: usize) -> Result<(), GraphError> {
+        if v >= self.n_vertices {
+            return Err(GraphError::VertexOutOfBounds(v));
         }
 
-        // Known case: Any Hamiltonian graph is also traceable
-        if self.is_likely_hamiltonian(use_exact_connectivity) {
-            return true;
-        }
+        let shift = |u: usize| if u > v { u - 1 } else { u };
+
+        self.degree_stats.take();
+        // v's square disappears, and each neighbor's degree drops from d to d - 1
+        let lost: usize = self.edges[v].iter().map(|&u| 2 * self.edges[u].len() - 1).sum();
+        self.zagreb_index -= self.edges[v].len().pow(2) + lost;
+        self.n_edges -= self.edges[v].len();
+        self.edges = (0..self.n_vertices)
+            .filter(|&u| u != v)
+            .map(|u| {
+                self.edges[u]
+                    .iter()
+                    .filter(|&&w| w != v)
+                    .map(|&w| shift(w))
+                    .collect()
+            })
+            .collect();
+        self.weights = self
+            .weights
+            .iter()
+            .filter(|&(&(a, b), _)| a != v && b != v)
+            .map(|(&(a, b), &w)| ((shift(a), shift(b)), w))
+            .collect();
+        self.n_vertices -= 1;
 
-        // Known case: Complete graphs are always traceable
-        if self.is_complete() {
-            return true;
-        }
+        Ok(())
+    }
 
-        // Known case: Path graphs are traceable by definition
-        if self.is_path() {
-            return true;
+    /// Contract the edge `{u, v}`, merging v into u
+    ///
+    /// u inherits every neighbor of v other than itself, and edges that would become parallel
+    /// collapse into one, keeping u's weight. v is then removed and re-indexed as in
+    /// `remove_vertex`, so if u > v the merged vertex ends up at index u - 1.
+    pub fn contract_edge(&mut self, u: usize, v: usize) -> Result<(), GraphError> {
+        if u >= self.n_vertices {
+            return Err(GraphError::VertexOutOfBounds(u));
         }
-
-        // Known case: Star graphs are traceable
-        if self.is_star() {
-            return true;
+        if v >= self.n_vertices {
+            return Err(GraphError::VertexOutOfBounds(v));
         }
-
-        // Special case: The Petersen graph is known to be traceable
-        if self.is_petersen() {
-            return true;
+        if u == v {
+            return Err(GraphError::SelfLoop(u));
         }
-
-        // Check k-connectivity first (k ≥ 1)
-        let k = 1;
-        if !self.is_k_connected(k, use_exact_connectivity) {
-            return false;
+        if !self.edges[u].contains(&v) {
+            return Err(GraphError::InvalidInput(format!(
+                "vertices {} and {} are not adjacent",
+                u, v
+            )));
         }
 
-        // Dirac-like condition for traceability: If minimum degree ≥ (n-1)/2, the graph is traceable
-        if self.min_degree() >= (self.n_vertices - 1) / 2 {
-            return true;
-        }
+        let moved: Vec<usize> = self.edges[v]
+            .iter()
+            .filter(|&&w| w != u && !self.edges[u].contains(&w))
+            .cloned()
+            .collect();
 
-        // The paper specifies n ≥ 9 for Theorem 2
-        if self.n_vertices < 9 {
-            // For smaller graphs, we'll use a simpler criterion
-            return self.min_degree() >= (self.n_vertices - 1) / 2;
+        for w in moved {
+            self.insert_edge_unchecked(u, w);
+            if let Some(weight) = self.weights.get(&(v.min(w), v.max(w))).copied() {
+                self.weights.insert((u.min(w), u.max(w)), weight);
+            }
         }
 
-        let delta = self.min_degree();
-        let delta_max = self.max_degree();
-        let n = self.n_vertices;
-        let e = self.n_edges;
-        let z1 = self.first_zagreb_index();
+        // Drops the contracted edge and v's remaining (now duplicated) edges
+        self.remove_vertex(v)
+    }
 
-        // Apply Theorem 2 from the paper
-        let part1 = (n - k - 2) * delta_max * delta_max;
-        let part2 = (e * e) / (k + 2);
-        let part3 = ((n - k - 2) as f64).sqrt() - (delta as f64).sqrt();
-        let part3_squared = part3 * part3;
-        let threshold = part1 + part2 + (part3_squared * e as f64) as usize;
+    /// Remove every edge while keeping the vertices
+    /// The adjacency sets keep their allocated capacity, so the graph can be refilled cheaply
+    pub fn clear_edges(&mut self) {
+        for neighbors in self.edges.iter_mut() {
+            neighbors.clear();
+        }
+        self.weights.clear();
+        self.n_edges = 0;
+        self.zagreb_index = 0;
+        self.degree_stats.take();
+    }
 
-        z1 >= threshold
+    /// Reset to an empty graph on the same vertex count
+    /// Unlike `clear_edges`, this also releases the memory held by the adjacency sets
+    pub fn clear(&mut self) {
+        *self = Graph::new(self.n_vertices);
+    }
+
+    /// Add an edge between vertices u and v with weight w
+    /// If the edge already exists its weight is replaced. Edges added with `add_edge` have
+    /// weight 1.0, so an all-1.0 weighted graph behaves exactly like an unweighted one.
+    pub fn add_weighted_edge(&mut self, u: usize, v: usize, w: f64) -> Result<(), &'static str> {
+        if !w.is_finite() {
+            return Err("Edge weight must be finite");
+        }
+
+        self.add_edge(u, v)?;
+
+        let key = (u.min(v), u.max(v));
+        if w == 1.0 {
+            self.weights.remove(&key);
+        } else {
+            self.weights.insert(key, w);
+        }
+
+        Ok(())
+    }
+
+    /// Check if u and v are adjacent
+    /// Out-of-bounds vertices are reported as not adjacent rather than as an error
+    pub fn has_edge(&self, u: usize, v: usize) -> bool {
+        self.edges.get(u).is_some_and(|neighbors| neighbors.contains(&v))
+    }
+
+    /// Get the weight of the edge between u and v
+    /// Returns None if there is no such edge
+    pub fn edge_weight(&self, u: usize, v: usize) -> Option<f64> {
+        if !self.has_edge(u, v) {
+            return None;
+        }
+
+        Some(*self.weights.get(&(u.min(v), u.max(v))).unwrap_or(&1.0))
+    }
+
+    /// Get the weighted degree of a vertex, the sum of the weights of its incident edges
+    pub fn weighted_degree(&self, v: usize) -> Result<f64, &'static str> {
+        if v >= self.n_vertices {
+            return Err("Vertex index out of bounds");
+        }
+
+        Ok(self.edges[v]
+            .iter()
+            .map(|&u| self.edge_weight(v, u).unwrap())
+            .sum())
+    }
+
+    /// Calculate the weighted first Zagreb index, the sum of squared weighted degrees
+    /// With every weight equal to 1.0 this equals `first_zagreb_index`
+    pub fn weighted_zagreb_index(&self) -> f64 {
+        (0..self.n_vertices)
+            .map(|v| {
+                let d = self.weighted_degree(v).unwrap();
+                d * d
+            })
+            .sum()
+    }
+
+    /// Get the degree of a vertex
+    pub fn degree(&self, v: usize) -> Result<usize, &'static str> {
+        if v >= self.n_vertices {
+            return Err("Vertex index out of bounds");
+        }
+
+        Ok(self.edges[v].len())
+    }
+
+    /// Get the degree of a vertex the caller has already checked to be in bounds
+    /// Used by internal loops in place of the checked `degree`
+    fn degree_unchecked(&self, v: usize) -> usize {
+        debug_assert!(v < self.n_vertices);
+        self.edges[v].len()
+    }
+
+    /// Get the first Zagreb index of the graph, the sum of d(v)² over all vertices
+    /// The index is maintained as edges change, so this is O(1)
+    pub fn first_zagreb_index(&self) -> usize {
+        self.zagreb_index
+    }
+
+    /// Calculate the second Zagreb index, the sum of d(u)·d(v) over all edges
+    pub fn second_zagreb_index(&self) -> usize {
+        (0..self.n_vertices)
+            .flat_map(|u| self.edges[u].iter().filter(move |&&v| v > u).map(move |&v| (u, v)))
+            .map(|(u, v)| self.edges[u].len() * self.edges[v].len())
+            .sum()
+    }
+
+    /// Calculate the hyper-Zagreb index, the sum of (d(u) + d(v))² over all edges
+    pub fn hyper_zagreb_index(&self) -> usize {
+        self.edge_list()
+            .into_iter()
+            .map(|(u, v)| (self.edges[u].len() + self.edges[v].len()).pow(2))
+            .sum()
+    }
+
+    /// Calculate the modified first Zagreb index, the sum of 1 / d(v)² over all vertices
+    /// Isolated vertices are skipped
+    pub fn modified_first_zagreb_index(&self) -> f64 {
+        self.edges
+            .iter()
+            .map(|neighbors| neighbors.len())
+            .filter(|&d| d > 0)
+            .map(|d| 1.0 / (d * d) as f64)
+            .sum()
+    }
+
+    /// Get the minimum degree of the graph
+    /// The value is cached until the edges change
+    pub fn min_degree(&self) -> usize {
+        self.degree_stats().min_degree
+    }
+
+    /// Get the maximum degree of the graph
+    /// The value is cached until the edges change
+    pub fn max_degree(&self) -> usize {
+        self.degree_stats().max_degree
+    }
+
+    /// Get a vertex of minimum degree, the lowest-indexed one if several tie
+    /// Returns None for a graph with no vertices
+    pub fn min_degree_vertex(&self) -> Option<usize> {
+        (0..self.n_vertices).min_by_key(|&v| self.degree_unchecked(v))
+    }
+
+    /// Get a vertex of maximum degree, the lowest-indexed one if several tie
+    /// Returns None for a graph with no vertices
+    pub fn max_degree_vertex(&self) -> Option<usize> {
+        // max_by_key keeps the last maximum, so prefer lower indices explicitly
+        (0..self.n_vertices).max_by_key(|&v| (self.degree_unchecked(v), std::cmp::Reverse(v)))
+    }
+
+    /// Get every vertex sorted by degree, ascending or descending
+    /// Vertices of equal degree are always listed by increasing index
+    pub fn vertices_by_degree(&self, ascending: bool) -> Vec<usize> {
+        let mut vertices: Vec<usize> = (0..self.n_vertices).collect();
+        if ascending {
+            vertices.sort_by_key(|&v| self.degree_unchecked(v));
+        } else {
+            vertices.sort_by_key(|&v| std::cmp::Reverse(self.degree_unchecked(v)));
+        }
+        vertices
+    }
+
+    /// Get the vertices of the k-core, the largest subgraph in which every vertex has degree
+    /// at least k
+    /// Found by repeatedly deleting vertices of degree below k; the result is sorted and may be
+    /// empty. Every vertex is in the 0-core.
+    pub fn k_core(&self, k: usize) -> Vec<usize> {
+        let core = self.core_number();
+        (0..self.n_vertices).filter(|&v| core[v] >= k).collect()
+    }
+
+    /// Get the core number of every vertex, the largest k for which it lies in the k-core
+    /// Uses the O(n + m) bucket algorithm of Batagelj and Zaversnik, which peels vertices in
+    /// order of their current degree
+    pub fn core_number(&self) -> Vec<usize> {
+        let n = self.n_vertices;
+        let mut degree: Vec<usize> = (0..n).map(|v| self.degree_unchecked(v)).collect();
+        let max_degree = degree.iter().copied().max().unwrap_or(0);
+
+        // Bucket-sort the vertices by degree: bin[d] is where degree d starts in `order`
+        let mut bin = vec![0; max_degree + 1];
+        for &d in &degree {
+            bin[d] += 1;
+        }
+        let mut start = 0;
+        for count in bin.iter_mut() {
+            let size = *count;
+            *count = start;
+            start += size;
+        }
+        let mut order = vec![0; n];
+        let mut position = vec![0; n];
+        for v in 0..n {
+            position[v] = bin[degree[v]];
+            order[position[v]] = v;
+            bin[degree[v]] += 1;
+        }
+        for d in (1..=max_degree).rev() {
+            bin[d] = bin[d - 1];
+        }
+        if let Some(first) = bin.first_mut() {
+            *first = 0;
+        }
+
+        // Peel in order; lowering a neighbor's degree moves it to the front of its bucket
+        for i in 0..n {
+            let v = order[i];
+            for &u in &self.edges[v] {
+                if degree[u] > degree[v] {
+                    let du = degree[u];
+                    let pu = position[u];
+                    let pw = bin[du];
+                    let w = order[pw];
+                    if u != w {
+                        order.swap(pu, pw);
+                        position[u] = pw;
+                        position[w] = pu;
+                    }
+                    bin[du] += 1;
+                    degree[u] -= 1;
+                }
+            }
+        }
+
+        degree
+    }
+
+    /// Get the cached degree summaries, scanning the vertices if they are not yet known
+    /// With the `rayon` feature the scans run in parallel over the vertices
+    fn degree_stats(&self) -> &DegreeStats {
+        self.degree_stats.get_or_init(|| {
+            #[cfg(feature = "rayon")]
+            {
+                DegreeStats {
+                    min_degree: self.min_degree_parallel(),
+                    max_degree: self.max_degree_parallel(),
+                }
+            }
+            #[cfg(not(feature = "rayon"))]
+            {
+                DegreeStats {
+                    min_degree: self.min_degree_serial(),
+                    max_degree: self.max_degree_serial(),
+                }
+            }
+        })
+    }
+
+    /// Scan for the minimum degree without the cache, exposed for the degree-scan benchmarks
+    #[doc(hidden)]
+    pub fn min_degree_serial(&self) -> usize {
+        (0..self.n_vertices)
+            .map(|v| self.degree_unchecked(v))
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Scan for the maximum degree without the cache, exposed for the degree-scan benchmarks
+    #[doc(hidden)]
+    pub fn max_degree_serial(&self) -> usize {
+        (0..self.n_vertices)
+            .map(|v| self.degree_unchecked(v))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Parallel counterpart of `min_degree_serial`
+    #[cfg(feature = "rayon")]
+    #[doc(hidden)]
+    pub fn min_degree_parallel(&self) -> usize {
+        use rayon::prelude::*;
+
+        (0..self.n_vertices)
+            .into_par_iter()
+            .map(|v| self.edges[v].len())
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Parallel counterpart of `max_degree_serial`
+    #[cfg(feature = "rayon")]
+    #[doc(hidden)]
+    pub fn max_degree_parallel(&self) -> usize {
+        use rayon::prelude::*;
+
+        (0..self.n_vertices)
+            .into_par_iter()
+            .map(|v| self.edges[v].len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Get the average vertex degree, 2m / n
+    /// Returns 0.0 for graphs with fewer than 2 vertices
+    pub fn average_degree(&self) -> f64 {
+        if self.n_vertices < 2 {
+            return 0.0;
+        }
+
+        2.0 * self.n_edges as f64 / self.n_vertices as f64
+    }
+
+    /// Get the edge density, the fraction of the n(n-1)/2 possible edges that are present
+    /// Returns 0.0 for graphs with fewer than 2 vertices
+    pub fn density(&self) -> f64 {
+        if self.n_vertices < 2 {
+            return 0.0;
+        }
+
+        let max_edges = self.n_vertices * (self.n_vertices - 1) / 2;
+        self.n_edges as f64 / max_edges as f64
+    }
+
+    /// Get the gap between the maximum and minimum degree
+    /// Zero means the graph is regular; larger gaps make the Theorem 3 bound looser
+    pub fn regularity_gap(&self) -> usize {
+        self.max_degree() - self.min_degree()
+    }
+
+    /// Check if every vertex has the same degree
+    /// The graph with no vertices is not considered regular
+    pub fn is_regular(&self) -> bool {
+        self.regularity().is_some()
+    }
+
+    /// Get the common degree k if the graph is k-regular, or None otherwise
+    pub fn regularity(&self) -> Option<usize> {
+        let k = self.min_degree();
+        (self.n_vertices > 0 && k == self.max_degree()).then_some(k)
+    }
+
+    /// Get the parameters (n, k, λ, μ) if the graph is strongly regular
+    ///
+    /// The graph must be k-regular, every adjacent pair must have exactly λ common neighbors
+    /// and every non-adjacent pair exactly μ. Complete and edgeless graphs are excluded since
+    /// one of λ or μ is then undefined.
+    pub fn strongly_regular_parameters(&self) -> Option<(usize, usize, usize, usize)> {
+        let n = self.n_vertices;
+        let k = self.regularity()?;
+        if k == 0 || self.is_complete() {
+            return None;
+        }
+
+        let mut lambda = None;
+        let mut mu = None;
+
+        for u in 0..n {
+            let u_neighbors = &self.edges[u];
+            for v in (u + 1)..n {
+                let common = u_neighbors.intersection(&self.edges[v]).count();
+                let expected = if u_neighbors.contains(&v) { &mut lambda } else { &mut mu };
+
+                match *expected {
+                    None => *expected = Some(common),
+                    Some(value) if value != common => return None,
+                    Some(_) => {}
+                }
+            }
+        }
+
+        Some((n, k, lambda?, mu?))
+    }
+
+    /// Count the triangles in the graph, each counted once
+    pub fn triangle_count(&self) -> usize {
+        let mut triangles = 0;
+
+        // Count each triangle u < v < w from its lowest edge
+        for u in 0..self.n_vertices {
+            for &v in self.edges[u].iter().filter(|&&v| v > u) {
+                triangles += self.edges[u]
+                    .intersection(&self.edges[v])
+                    .filter(|&&w| w > v)
+                    .count();
+            }
+        }
+
+        triangles
+    }
+
+    /// Calculate the global clustering coefficient (transitivity)
+    /// This is 3 × triangles / connected triples, where a connected triple is a path of two
+    /// edges. Returns 0.0 if the graph has no connected triples.
+    pub fn clustering_coefficient(&self) -> f64 {
+        let triples: usize = self
+            .edges
+            .iter()
+            .map(|neighbors| neighbors.len() * neighbors.len().saturating_sub(1) / 2)
+            .sum();
+
+        if triples == 0 {
+            return 0.0;
+        }
+
+        3.0 * self.triangle_count() as f64 / triples as f64
+    }
+
+    /// Calculate the girth, the length of the shortest cycle
+    /// A BFS from every vertex finds the shortest cycle through it, so this runs in O(n·m).
+    /// Returns None for a forest, which has no cycles.
+    pub fn girth(&self) -> Option<usize> {
+        use std::collections::VecDeque;
+
+        let mut girth: Option<usize> = None;
+
+        for root in 0..self.n_vertices {
+            let mut distance = vec![None; self.n_vertices];
+            let mut parent = vec![None; self.n_vertices];
+            let mut queue = VecDeque::new();
+            distance[root] = Some(0);
+            queue.push_back(root);
+
+            while let Some(u) = queue.pop_front() {
+                let du: usize = distance[u].unwrap();
+
+                // No shorter cycle can be found from deeper vertices
+                if girth.is_some_and(|g| 2 * du + 1 >= g) {
+                    break;
+                }
+
+                for &v in &self.edges[u] {
+                    match distance[v] {
+                        None => {
+                            distance[v] = Some(du + 1);
+                            parent[v] = Some(u);
+                            queue.push_back(v);
+                        }
+                        // A non-tree edge closes a cycle through the root
+                        Some(dv) if parent[u] != Some(v) => {
+                            let length = du + dv + 1;
+                            girth = Some(girth.map_or(length, |g| g.min(length)));
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+
+        girth
+    }
+
+    /// Check if the graph is the Petersen graph
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zagreb_lib::Graph;
+    ///
+    /// assert!(Graph::petersen().is_petersen());
+    /// ```
+    pub fn is_petersen(&self) -> bool {
+        // The Petersen graph has exactly 10 vertices and 15 edges
+        if self.n_vertices != 10 || self.n_edges != 15 {
+            return false;
+        }
+
+        // It's 3-regular (every vertex has degree 3)
+        if self.min_degree() != 3 || self.max_degree() != 3 {
+            return false;
+        }
+
+        // The Petersen graph is the only 3-regular graph on 10 vertices with girth 5
+        self.girth() == Some(5)
+    }
+
+    /// Check if the graph is k-connected, using the fast approximation
+    /// Equivalent to `is_k_connected_with(k, false)`
+    pub fn is_k_connected(&self, k: usize) -> bool {
+        self.is_k_connected_with(k, false)
+    }
+
+    /// Check if the graph is k-connected (wrapper function)
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The connectivity parameter to check
+    /// * `use_exact` - Whether to use the exact algorithm (slower but more accurate) or the approximation
+    ///
+    /// # Returns
+    ///
+    /// `true` if the graph is k-connected, `false` otherwise
+    pub fn is_k_connected_with(&self, k: usize, use_exact: bool) -> bool {
+        // Handle the complete graph case directly for robustness
+        if self.is_complete() {
+            return k < self.n_vertices;
+        }
+
+        if use_exact {
+            self.is_k_connected_exact(k)
+        } else {
+            self.is_k_connected_approx(k)
+        }
+    }
+
+    /// Check if the graph is k-connected using fast sufficient conditions where possible
+    /// Known graph families and a minimum-degree condition answer most dense graphs directly;
+    /// the remaining graphs fall back to `is_k_connected_exact`, so a `true` result is never a
+    /// guess
+    pub fn is_k_connected_approx(&self, k: usize) -> bool {
+        // A graph with n vertices cannot be k-connected if k > n-1; this also rejects n = 0
+        if k >= self.n_vertices {
+            return false;
+        }
+
+        // A necessary condition: minimum degree must be at least k
+        if self.min_degree() < k {
+            return false;
+        }
+
+        // For k=1, just check if the graph is connected
+        if k == 1 {
+            return self.is_connected();
+        }
+
+        // Complete graphs are (n-1)-connected but not n-connected
+        if self.is_complete() {
+            return k < self.n_vertices;
+        }
+
+        // For cycle graphs: they are 2-connected but not 3-connected
+        if self.is_cycle() {
+            return k <= 2;
+        }
+
+        // For path graphs: they are only 1-connected
+        if self.is_path() {
+            return k <= 1;
+        }
+
+        // For star graphs: they are only 1-connected
+        if self.is_star() {
+            return k <= 1;
+        }
+
+        // Sufficient condition: if δ ≥ (n + k - 2) / 2, any two vertices share enough neighbors
+        // that no set of k - 1 vertices can separate them. Edge counts alone are not enough,
+        // since a barbell (two cliques joined by a single edge) is dense but only 1-connected.
+        if 2 * self.min_degree() + 2 >= self.n_vertices + k {
+            return true;
+        }
+
+        // Otherwise the cheap tests are inconclusive, so fall back to the exact check
+        self.is_k_connected_exact(k)
+    }
+
+    /// Check if the graph is k-connected using an exact algorithm based on Menger's theorem
+    /// This is slower but gives correct results for all graphs
+    pub fn is_k_connected_exact(&self, k: usize) -> bool {
+        // A graph with n vertices cannot be k-connected if k > n-1; this also rejects n = 0
+        if k >= self.n_vertices {
+            return false;
+        }
+
+        // A necessary condition: minimum degree must be at least k
+        if self.min_degree() < k {
+            return false;
+        }
+
+        // Special case for complete graphs - they are (n-1)-connected but not n-connected
+        if self.is_complete() {
+            return k < self.n_vertices;
+        }
+
+        // For k=1, just check if the graph is connected (optimization)
+        if k == 1 {
+            return self.is_connected();
+        }
+
+        // Implementation of the exact algorithm using flow networks
+        self.mengers_theorem_check(k)
+    }
+
+    /// Implements an exact check for k-connectivity using Menger's theorem
+    /// Menger's theorem states that a graph is k-vertex-connected if and only if
+    /// any pair of vertices is connected by at least k vertex-disjoint paths.
+    fn mengers_theorem_check(&self, k: usize) -> bool {
+        // Special cases
+        if self.n_vertices <= k {
+            return false; // Can't be k-connected with only k vertices
+        }
+
+        // A necessary condition: minimum degree must be at least k
+        if self.min_degree() < k {
+            return false;
+        }
+
+        // For k=1, just check if the graph is connected (optimization)
+        if k == 1 {
+            return self.is_connected();
+        }
+
+        // Special cases for common graph types
+        if self.is_cycle() {
+            return k <= 2; // Cycle graphs are 2-connected but not 3-connected
+        }
+
+        if self.is_complete() {
+            return k < self.n_vertices; // Complete graphs are (n-1)-connected
+        }
+
+        // For each pair of distinct vertices, check if they have at least k vertex-disjoint paths
+        for s in 0..self.n_vertices {
+            for t in (s + 1)..self.n_vertices {
+                if !self.has_vertex_disjoint_paths(s, t, k) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Find the largest k ≤ `upper` for which the graph is k-connected
+    ///
+    /// Equivalent to checking `is_k_connected(k)` for k = 1..=upper, but each pair of vertices
+    /// is visited once: the current bound is tested with the greedy disjoint-path check, and
+    /// only pairs that fail it pay for an exact max-flow, which lowers the bound for the
+    /// remaining pairs. Returns 0 for disconnected graphs and graphs with fewer than 2 vertices.
+    pub fn max_k_connectivity(&self, upper: usize) -> usize {
+        if self.n_vertices <= 1 || !self.is_connected() {
+            return 0;
+        }
+
+        if self.is_complete() {
+            return upper.min(self.n_vertices - 1);
+        }
+
+        // κ(G) ≤ δ(G), and a connected graph is always 1-connected
+        let mut best = upper.min(self.min_degree());
+        for s in 0..self.n_vertices {
+            for t in (s + 1)..self.n_vertices {
+                if best <= 1 {
+                    return best;
+                }
+                if !self.has_vertex_disjoint_paths(s, t, best) {
+                    best = self.local_vertex_connectivity(s, t);
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Calculate the vertex connectivity κ(G)
+    /// This is the minimum number of vertices whose removal disconnects the graph, or n - 1 for
+    /// a complete graph. Complete graphs, cycles, paths and stars are answered directly; other
+    /// graphs return the largest k ≤ δ for which the Menger-based check succeeds.
+    pub fn vertex_connectivity(&self) -> usize {
+        if self.n_vertices <= 1 {
+            return 0;
+        }
+
+        if self.is_complete() {
+            return self.n_vertices - 1;
+        }
+
+        if !self.is_connected() {
+            return 0;
+        }
+
+        if self.is_cycle() {
+            return 2;
+        }
+
+        if self.is_path() || self.is_star() {
+            return 1;
+        }
+
+        // κ(G) ≤ δ(G), so only k up to the minimum degree can succeed
+        self.max_k_connectivity(self.min_degree())
+    }
+
+    /// Check if the graph is connected (1-connected)
+    /// A graph with no vertices is considered connected, as is a single isolated vertex
+    pub fn is_connected(&self) -> bool {
+        // There is no vertex to start the search from
+        if self.n_vertices == 0 {
+            return true;
+        }
+
+        use std::collections::VecDeque;
+
+        let mut visited = vec![false; self.n_vertices];
+        let mut visited_count = 1;
+        let mut queue = VecDeque::new();
+
+        // Start BFS from vertex 0
+        visited[0] = true;
+        queue.push_back(0);
+
+        while let Some(v) = queue.pop_front() {
+            for &neighbor in &self.edges[v] {
+                if !visited[neighbor] {
+                    visited[neighbor] = true;
+                    visited_count += 1;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        // If we visited all vertices, the graph is connected
+        visited_count == self.n_vertices
+    }
+
+    /// Get the blocks (maximal biconnected subgraphs) of the graph
+    /// Each block is sorted; bridges form two-vertex blocks and isolated vertices single-vertex blocks
+    pub fn biconnected_components(&self) -> Vec<Vec<usize>> {
+        self.biconnected_search().blocks
+    }
+
+    /// Get the articulation (cut) vertices, whose removal increases the number of components
+    pub fn articulation_points(&self) -> Vec<usize> {
+        let search = self.biconnected_search();
+        (0..self.n_vertices).filter(|&v| search.is_cut_vertex[v]).collect()
+    }
+
+    /// Build the block-cut tree of the graph
+    ///
+    /// The tree has one node per block followed by one node per cut vertex, with an edge
+    /// between a block and each cut vertex it contains. The returned labels describe what each
+    /// node of the tree stands for. For a disconnected graph the result is a forest.
+    pub fn block_cut_tree(&self) -> (Graph, Vec<BlockCutNode>) {
+        let search = self.biconnected_search();
+        let cut_vertices: Vec<usize> = (0..self.n_vertices)
+            .filter(|&v| search.is_cut_vertex[v])
+            .collect();
+
+        let block_count = search.blocks.len();
+        let cut_index: HashMap<usize, usize> = cut_vertices
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| (v, block_count + i))
+            .collect();
+
+        let mut tree = Graph::new(block_count + cut_vertices.len());
+        for (b, block) in search.blocks.iter().enumerate() {
+            for v in block {
+                if let Some(&c) = cut_index.get(v) {
+                    tree.add_edge(b, c).unwrap();
+                }
+            }
+        }
+
+        let labels = search
+            .blocks
+            .into_iter()
+            .map(BlockCutNode::Block)
+            .chain(cut_vertices.into_iter().map(BlockCutNode::CutVertex))
+            .collect();
+
+        (tree, labels)
+    }
+
+    /// Run Tarjan's algorithm for blocks and articulation points over every component
+    fn biconnected_search(&self) -> BiconnectedSearch {
+        let mut search = BiconnectedSearch {
+            discovery: vec![usize::MAX; self.n_vertices],
+            low: vec![usize::MAX; self.n_vertices],
+            timer: 0,
+            edge_stack: Vec::new(),
+            is_cut_vertex: vec![false; self.n_vertices],
+            blocks: Vec::new(),
+        };
+
+        for root in 0..self.n_vertices {
+            if search.discovery[root] != usize::MAX {
+                continue;
+            }
+
+            if self.edges[root].is_empty() {
+                search.discovery[root] = search.timer;
+                search.timer += 1;
+                search.blocks.push(vec![root]);
+                continue;
+            }
+
+            self.biconnected_dfs(root, &mut search);
+        }
+
+        search
+    }
+
+    /// DFS of Tarjan's biconnected components algorithm from `root`
+    ///
+    /// Uses an explicit stack of (vertex, parent, sorted neighbors, next neighbor position,
+    /// child count) frames, since the DFS path can be as long as the component
+    fn biconnected_dfs(&self, root: usize, search: &mut BiconnectedSearch) {
+        search.discovery[root] = search.timer;
+        search.low[root] = search.timer;
+        search.timer += 1;
+
+        let mut stack = vec![(root, None, self.sorted_neighbors(root), 0, 0)];
+
+        while let Some((u, parent, neighbors, position, children)) = stack.last_mut() {
+            let (u, parent) = (*u, *parent);
+            let Some(&v) = neighbors.get(*position) else {
+                stack.pop();
+
+                // Return to the parent p, which separates u's subtree from the rest if no
+                // back edge from that subtree climbs above it, closing a block
+                let Some(&(p, grandparent, _, _, p_children)) = stack.last() else {
+                    continue;
+                };
+                search.low[p] = search.low[p].min(search.low[u]);
+
+                if search.low[u] >= search.discovery[p] {
+                    if grandparent.is_some() || p_children > 1 {
+                        search.is_cut_vertex[p] = true;
+                    }
+
+                    let mut block = HashSet::new();
+                    while let Some((a, b)) = search.edge_stack.pop() {
+                        block.insert(a);
+                        block.insert(b);
+                        if (a, b) == (p, u) {
+                            break;
+                        }
+                    }
+                    let mut block: Vec<usize> = block.into_iter().collect();
+                    block.sort_unstable();
+                    search.blocks.push(block);
+                }
+                continue;
+            };
+            *position += 1;
+
+            if search.discovery[v] == usize::MAX {
+                *children += 1;
+                search.edge_stack.push((u, v));
+                search.discovery[v] = search.timer;
+                search.low[v] = search.timer;
+                search.timer += 1;
+                stack.push((v, Some(u), self.sorted_neighbors(v), 0, 0));
+            } else if Some(v) != parent && search.discovery[v] < search.discovery[u] {
+                // Back edge to an ancestor
+                search.edge_stack.push((u, v));
+                search.low[u] = search.low[u].min(search.discovery[v]);
+            }
+        }
+    }
+
+    /// Calculate the effective resistance between vertices s and t
+    ///
+    /// Every edge is treated as a unit resistor. The value is found by solving the grounded
+    /// Laplacian system of the component containing s and t, and is low when many redundant
+    /// paths connect the pair. Returns None if s and t are disconnected or out of bounds.
+    pub fn effective_resistance(&self, s: usize, t: usize) -> Option<f64> {
+        if s >= self.n_vertices || t >= self.n_vertices {
+            return None;
+        }
+        if s == t {
+            return Some(0.0);
+        }
+
+        let distances = self.bfs_distances(s);
+        distances[t]?;
+
+        // Index the component of s, leaving out the grounded vertex t
+        let component: Vec<usize> = (0..self.n_vertices)
+            .filter(|&v| v != t && distances[v].is_some())
+            .collect();
+        let index: HashMap<usize, usize> =
+            component.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+
+        let size = component.len();
+        let mut laplacian = vec![vec![0.0; size]; size];
+        for (i, &u) in component.iter().enumerate() {
+            let neighbors = &self.edges[u];
+            laplacian[i][i] = neighbors.len() as f64;
+            for v in neighbors {
+                if let Some(&j) = index.get(v) {
+                    laplacian[i][j] = -1.0;
+                }
+            }
+        }
+
+        // Inject a unit current at s and extract it at the grounded t
+        let mut current = vec![0.0; size];
+        current[index[&s]] = 1.0;
+
+        let potentials = solve_linear_system(laplacian, current)?;
+        Some(potentials[index[&s]])
+    }
+
+    /// Check if the graph can be drawn in the plane without crossing edges
+    ///
+    /// Dense graphs are rejected by Euler's bounds: a planar graph on n ≥ 3 vertices has at
+    /// most 3n - 6 edges, or 2n - 4 if it has no triangles, which already rules out K5 and
+    /// K3,3. Otherwise each block (biconnected component) is embedded with the
+    /// Demoucron–Malgrange–Pertuiset path-addition algorithm, which is exact, so every graph
+    /// containing a K5 or K3,3 minor is caught. It takes roughly O(n³) time, so it is meant
+    /// for small and medium graphs and is not a linear-time Boyer–Myrvold implementation.
+    pub fn is_planar(&self) -> bool {
+        let n = self.n_vertices;
+        if n >= 3 && self.n_edges > 3 * n - 6 {
+            return false;
+        }
+        if n >= 3 && self.n_edges > 2 * n - 4 && self.triangle_count() == 0 {
+            return false;
+        }
+
+        // A graph is planar iff every block is, and blocks on at most 4 vertices always are
+        self.biconnected_components()
+            .iter()
+            .filter(|block| block.len() > 4)
+            .all(|block| self.induced_subgraph_unchecked(block).is_biconnected_planar())
+    }
+
+    /// Planarity test for a biconnected graph on at least 3 vertices
+    ///
+    /// Starts from an embedded cycle and repeatedly embeds a path through one fragment into a
+    /// face containing all of the fragment's attachments. Fragments with a single admissible
+    /// face go first; the graph is non-planar exactly when some fragment has no admissible
+    /// face. Every face of a biconnected embedding is a cycle, so faces are stored as cyclic
+    /// vertex sequences.
+    fn is_biconnected_planar(&self) -> bool {
+        use std::collections::VecDeque;
+
+        let n = self.n_vertices;
+        if self.n_edges > 3 * n - 6 {
+            return false;
+        }
+
+        // Initial cycle: the edge 0 -- v closed by a path from v back to 0 avoiding that edge
+        let v = *self.edges[0].iter().min().unwrap();
+        let mut parent = vec![usize::MAX; n];
+        parent[v] = v;
+        let mut queue = VecDeque::from([v]);
+        while let Some(u) = queue.pop_front() {
+            for &w in &self.edges[u] {
+                if parent[w] == usize::MAX && !(u == v && w == 0) {
+                    parent[w] = u;
+                    queue.push_back(w);
+                }
+            }
+        }
+        let mut cycle = vec![0];
+        while *cycle.last().unwrap() != v {
+            cycle.push(parent[*cycle.last().unwrap()]);
+        }
+
+        let mut embedded = vec![false; n];
+        let mut embedded_edges = HashSet::new();
+        for (i, &u) in cycle.iter().enumerate() {
+            let w = cycle[(i + 1) % cycle.len()];
+            embedded[u] = true;
+            embedded_edges.insert((u.min(w), u.max(w)));
+        }
+        let mut faces = vec![cycle.clone(), cycle];
+
+        loop {
+            let fragments = self.planar_fragments(&embedded, &embedded_edges);
+            if fragments.is_empty() {
+                return true;
+            }
+
+            let face_sets: Vec<HashSet<usize>> =
+                faces.iter().map(|face| face.iter().cloned().collect()).collect();
+            let mut chosen = None;
+            for fragment in &fragments {
+                let mut admissible = face_sets
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, face)| fragment.attachments.iter().all(|a| face.contains(a)))
+                    .map(|(f, _)| f);
+                let first = match admissible.next() {
+                    Some(f) => f,
+                    None => return false,
+                };
+                if admissible.next().is_none() {
+                    chosen = Some((fragment, first));
+                    break;
+                }
+                if chosen.is_none() {
+                    chosen = Some((fragment, first));
+                }
+            }
+            let (fragment, face) = chosen.unwrap();
+
+            let path = self.fragment_path(fragment, &embedded);
+            for (i, &u) in path.iter().enumerate() {
+                embedded[u] = true;
+                if let Some(&w) = path.get(i + 1) {
+                    embedded_edges.insert((u.min(w), u.max(w)));
+                }
+            }
+
+            let (first, second) = split_face(&faces[face], &path);
+            faces[face] = first;
+            faces.push(second);
+        }
+    }
+
+    /// Get the fragments of a graph relative to its embedded part
+    /// Every unembedded edge between embedded vertices is a fragment on its own, as is every
+    /// component of the unembedded vertices together with its edges to the embedded part.
+    fn planar_fragments(
+        &self,
+        embedded: &[bool],
+        embedded_edges: &HashSet<(usize, usize)>,
+    ) -> Vec<PlanarFragment> {
+        let mut fragments = Vec::new();
+
+        for u in (0..self.n_vertices).filter(|&u| embedded[u]) {
+            for &w in self.edges[u].iter().filter(|&&w| embedded[w] && u < w) {
+                if !embedded_edges.contains(&(u, w)) {
+                    fragments.push(PlanarFragment {
+                        component: Vec::new(),
+                        attachments: vec![u, w],
+                    });
+                }
+            }
+        }
+
+        let mut seen = embedded.to_vec();
+        for start in 0..self.n_vertices {
+            if seen[start] {
+                continue;
+            }
+
+            seen[start] = true;
+            let mut component = vec![start];
+            let mut attachments = HashSet::new();
+            let mut next = 0;
+            while next < component.len() {
+                let u = component[next];
+                next += 1;
+                for &w in &self.edges[u] {
+                    if embedded[w] {
+                        attachments.insert(w);
+                    } else if !seen[w] {
+                        seen[w] = true;
+                        component.push(w);
+                    }
+                }
+            }
+
+            let mut attachments: Vec<usize> = attachments.into_iter().collect();
+            attachments.sort_unstable();
+            fragments.push(PlanarFragment {
+                component,
+                attachments,
+            });
+        }
+
+        fragments
+    }
+
+    /// Find a path through a fragment between two of its distinct attachments
+    /// In a biconnected graph every fragment has at least two attachments
+    fn fragment_path(&self, fragment: &PlanarFragment, embedded: &[bool]) -> Vec<usize> {
+        use std::collections::VecDeque;
+
+        let a = fragment.attachments[0];
+        if fragment.component.is_empty() {
+            return vec![a, fragment.attachments[1]];
+        }
+
+        let in_component: HashSet<usize> = fragment.component.iter().cloned().collect();
+        let start = *self.edges[a]
+            .iter()
+            .filter(|w| in_component.contains(w))
+            .min()
+            .unwrap();
+        let mut parent = HashMap::from([(start, a)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(u) = queue.pop_front() {
+            if let Some(&b) = self.edges[u].iter().filter(|&&w| embedded[w] && w != a).min() {
+                let mut path = vec![b, u];
+                while *path.last().unwrap() != a {
+                    path.push(parent[path.last().unwrap()]);
+                }
+                path.reverse();
+                return path;
+            }
+            for &w in &self.edges[u] {
+                if in_component.contains(&w) && !parent.contains_key(&w) {
+                    parent.insert(w, u);
+                    queue.push_back(w);
+                }
+            }
+        }
+
+        unreachable!("a fragment of a biconnected graph has two attachments")
+    }
+
+    /// Check if the graph is bipartite (has no odd cycle)
+    pub fn is_bipartite(&self) -> bool {
+        self.bipartition().is_some()
+    }
+
+    /// Split the vertices into two sides with every edge running between them
+    /// Each component is 2-colored by BFS, with its lowest vertex on the first side. Both sides
+    /// are sorted. Returns None if the graph contains an odd cycle.
+    pub fn bipartition(&self) -> Option<(Vec<usize>, Vec<usize>)> {
+        use std::collections::VecDeque;
+
+        let mut side: Vec<Option<bool>> = vec![None; self.n_vertices];
+
+        for start in 0..self.n_vertices {
+            if side[start].is_some() {
+                continue;
+            }
+
+            side[start] = Some(false);
+            let mut queue = VecDeque::from([start]);
+
+            while let Some(u) = queue.pop_front() {
+                let color = side[u].unwrap();
+                for &v in &self.edges[u] {
+                    match side[v] {
+                        None => {
+                            side[v] = Some(!color);
+                            queue.push_back(v);
+                        }
+                        // Both endpoints on the same side closes an odd cycle
+                        Some(c) if c == color => return None,
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+
+        let (first, second): (Vec<usize>, Vec<usize>) =
+            (0..self.n_vertices).partition(|&v| side[v] == Some(false));
+        Some((first, second))
+    }
+
+    /// Find a maximum matching, a largest set of edges no two of which share a vertex
+    ///
+    /// Bipartite graphs use Hopcroft–Karp; other graphs use Edmonds' blossom algorithm.
+    /// Each edge is returned as `(u, v)` with `u < v`, sorted lexicographically.
+    pub fn maximum_matching(&self) -> Vec<(usize, usize)> {
+        let mate = match self.bipartition() {
+            Some((left, _)) => self.hopcroft_karp(&left),
+            None => self.blossom_matching(),
+        };
+
+        (0..self.n_vertices)
+            .filter_map(|u| mate[u].filter(|&v| u < v).map(|v| (u, v)))
+            .collect()
+    }
+
+    /// Hopcroft–Karp maximum matching between `left` and the remaining vertices
+    /// Every edge must join `left` to the other side; returns each vertex's partner
+    fn hopcroft_karp(&self, left: &[usize]) -> Vec<Option<usize>> {
+        use std::collections::VecDeque;
+
+        // Depth-first search for an augmenting path from the free left vertex `root`, with an
+        // explicit stack of (left vertex, its neighbors, next neighbor position) frames since
+        // the path can be up to n/2 edges long
+        fn augment(
+            graph: &Graph,
+            root: usize,
+            mate: &mut [Option<usize>],
+            layer: &mut [usize],
+        ) -> bool {
+            let mut stack = vec![(root, graph.sorted_neighbors(root), 0)];
+
+            while let Some((u, neighbors, position)) = stack.last_mut() {
+                let u = *u;
+                let Some(&v) = neighbors.get(*position) else {
+                    // Dead end: drop u from the layered graph for the rest of this phase
+                    layer[u] = usize::MAX;
+                    stack.pop();
+                    continue;
+                };
+                *position += 1;
+
+                // Follow only edges into the next BFS layer, ending at a free right vertex
+                match mate[v] {
+                    None => {
+                        // Each frame's last tried neighbor is its edge on the path; flip them all
+                        for &(u, ref neighbors, position) in &stack {
+                            let v = neighbors[position - 1];
+                            mate[u] = Some(v);
+                            mate[v] = Some(u);
+                        }
+                        return true;
+                    }
+                    Some(w) if layer[w] == layer[u] + 1 => {
+                        stack.push((w, graph.sorted_neighbors(w), 0));
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            false
+        }
+
+        let mut mate = vec![None; self.n_vertices];
+        let mut layer = vec![usize::MAX; self.n_vertices];
+
+        loop {
+            // Layer the left vertices by alternating distance from the free ones
+            let mut queue = VecDeque::new();
+            for &u in left {
+                layer[u] = if mate[u].is_none() { 0 } else { usize::MAX };
+                if mate[u].is_none() {
+                    queue.push_back(u);
+                }
+            }
+
+            let mut found_free = false;
+            while let Some(u) = queue.pop_front() {
+                for &v in &self.edges[u] {
+                    match mate[v] {
+                        None => found_free = true,
+                        Some(w) if layer[w] == usize::MAX => {
+                            layer[w] = layer[u] + 1;
+                            queue.push_back(w);
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+
+            if !found_free {
+                return mate;
+            }
+
+            for &u in left {
+                if mate[u].is_none() {
+                    augment(self, u, &mut mate, &mut layer);
+                }
+            }
+        }
+    }
+
+    /// Edmonds' blossom algorithm for a maximum matching in a general graph
+    /// Returns each vertex's partner
+    fn blossom_matching(&self) -> Vec<Option<usize>> {
+        let n = self.n_vertices;
+        let mut search = BlossomSearch {
+            mate: vec![None; n],
+            parent: vec![None; n],
+            base: (0..n).collect(),
+            used: vec![false; n],
+            blossom: vec![false; n],
+        };
+
+        // A greedy start leaves fewer augmenting paths to search for
+        for u in 0..n {
+            if search.mate[u].is_none() {
+                let free = self.sorted_neighbors(u).into_iter().find(|&v| search.mate[v].is_none());
+                if let Some(v) = free {
+                    search.mate[u] = Some(v);
+                    search.mate[v] = Some(u);
+                }
+            }
+        }
+
+        for root in 0..n {
+            if search.mate[root].is_some() {
+                continue;
+            }
+
+            if let Some(mut v) = self.find_augmenting_path(root, &mut search) {
+                // Flip the matched and unmatched edges along the path back to the root
+                while let Some(p) = search.parent[v] {
+                    let next = search.mate[p];
+                    search.mate[v] = Some(p);
+                    search.mate[p] = Some(v);
+                    match next {
+                        Some(w) => v = w,
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        search.mate
+    }
+
+    /// Grow an alternating tree from the free vertex `root`, contracting odd cycles
+    /// Returns the free vertex at the end of an augmenting path, if one exists
+    fn find_augmenting_path(&self, root: usize, search: &mut BlossomSearch) -> Option<usize> {
+        use std::collections::VecDeque;
+
+        let n = self.n_vertices;
+        search.used.fill(false);
+        search.parent.fill(None);
+        for (v, base) in search.base.iter_mut().enumerate() {
+            *base = v;
+        }
+
+        search.used[root] = true;
+        let mut queue = VecDeque::from([root]);
+
+        while let Some(v) = queue.pop_front() {
+            for to in self.sorted_neighbors(v) {
+                if search.base[v] == search.base[to] || search.mate[v] == Some(to) {
+                    continue;
+                }
+
+                let closes_odd_cycle = to == root
+                    || search.mate[to].is_some_and(|m| search.parent[m].is_some());
+
+                if closes_odd_cycle {
+                    // Contract the blossom through v and to into its base
+                    let current_base = self.blossom_base(v, to, search);
+                    search.blossom.fill(false);
+                    self.mark_blossom_path(v, current_base, to, search);
+                    self.mark_blossom_path(to, current_base, v, search);
+
+                    for u in 0..n {
+                        if search.blossom[search.base[u]] {
+                            search.base[u] = current_base;
+                            if !search.used[u] {
+                                search.used[u] = true;
+                                queue.push_back(u);
+                            }
+                        }
+                    }
+                } else if search.parent[to].is_none() {
+                    search.parent[to] = Some(v);
+                    match search.mate[to] {
+                        None => return Some(to),
+                        Some(m) => {
+                            search.used[m] = true;
+                            queue.push_back(m);
+                        }
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Find the base of the blossom closed by the edge between even vertices a and b
+    /// This is their lowest common ancestor in the alternating tree
+    fn blossom_base(&self, a: usize, b: usize, search: &BlossomSearch) -> usize {
+        let mut on_root_path = vec![false; self.n_vertices];
+
+        let mut a = a;
+        loop {
+            a = search.base[a];
+            on_root_path[a] = true;
+            match search.mate[a] {
+                Some(m) => a = search.parent[m].unwrap(),
+                None => break,
+            }
+        }
+
+        let mut b = b;
+        loop {
+            b = search.base[b];
+            if on_root_path[b] {
+                return b;
+            }
+            b = search.parent[search.mate[b].unwrap()].unwrap();
+        }
+    }
+
+    /// Mark the blossom vertices on the tree path from v up to `base`, re-pointing odd
+    /// vertices so that paths through the blossom can later be augmented
+    fn mark_blossom_path(&self, v: usize, base: usize, child: usize, search: &mut BlossomSearch) {
+        let (mut v, mut child) = (v, child);
+        while search.base[v] != base {
+            let m = search.mate[v].unwrap();
+            search.blossom[search.base[v]] = true;
+            search.blossom[search.base[m]] = true;
+            search.parent[v] = Some(child);
+            child = m;
+            v = search.parent[m].unwrap();
+        }
+    }
+
+    /// Get the vertex sets of the connected components
+    /// Each component is sorted, and components are ordered by their smallest vertex
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut components = Vec::new();
+        let mut visited = vec![false; self.n_vertices];
+
+        for start in 0..self.n_vertices {
+            if visited[start] {
+                continue;
+            }
+
+            let component: Vec<usize> = self
+                .bfs_distances(start)
+                .into_iter()
+                .enumerate()
+                .filter(|(_, d)| d.is_some())
+                .map(|(v, _)| v)
+                .collect();
+
+            for &v in &component {
+                visited[v] = true;
+            }
+            components.push(component);
+        }
+
+        components
+    }
+
+    /// Detect communities with the Louvain modularity heuristic
+    ///
+    /// Each round moves vertices (or, after the first round, whole communities) to the
+    /// neighboring community with the largest modularity gain until no move helps, then
+    /// collapses every community into a single node and repeats on the smaller graph. It stops
+    /// once a round moves nothing. Nodes are visited in index order, so the result is
+    /// deterministic. Communities never span connected components, and a graph without edges
+    /// gives one community per vertex. Each community is sorted, and communities are ordered by
+    /// their smallest vertex.
+    pub fn louvain_communities(&self) -> Vec<Vec<usize>> {
+        let mut membership: Vec<usize> = (0..self.n_vertices).collect();
+
+        if self.n_edges > 0 {
+            let mut level = LouvainLevel {
+                neighbors: self
+                    .edges
+                    .iter()
+                    .map(|neighbors| {
+                        let mut sorted: Vec<(usize, f64)> =
+                            neighbors.iter().map(|&v| (v, 1.0)).collect();
+                        sorted.sort_unstable_by_key(|&(v, _)| v);
+                        sorted
+                    })
+                    .collect(),
+                self_loops: vec![0.0; self.n_vertices],
+            };
+
+            loop {
+                let (community, count, moved) = level.local_moves(self.n_edges as f64);
+                if !moved {
+                    break;
+                }
+                for c in membership.iter_mut() {
+                    *c = community[*c];
+                }
+                level = level.aggregate(&community, count);
+            }
+        }
+
+        let mut communities: Vec<Vec<usize>> = Vec::new();
+        let mut index_of = HashMap::new();
+        for (v, &c) in membership.iter().enumerate() {
+            let i = *index_of.entry(c).or_insert_with(|| {
+                communities.push(Vec::new());
+                communities.len() - 1
+            });
+            communities[i].push(v);
+        }
+
+        communities
+    }
+
+    /// Get the edges of a BFS spanning tree rooted at vertex 0
+    /// Each edge is a `(parent, child)` pair, listed in the order the children are discovered.
+    /// Returns None if the graph is disconnected.
+    pub fn spanning_tree(&self) -> Option<Vec<(usize, usize)>> {
+        if !self.is_connected() {
+            return None;
+        }
+
+        Some(self.spanning_forest().into_iter().flatten().collect())
+    }
+
+    /// Get a BFS spanning tree of every connected component
+    /// Trees are ordered like `connected_components` and rooted at the smallest vertex of their
+    /// component; an isolated vertex gives an empty tree. Edges are `(parent, child)` pairs.
+    pub fn spanning_forest(&self) -> Vec<Vec<(usize, usize)>> {
+        use std::collections::VecDeque;
+
+        let mut forest = Vec::new();
+        let mut visited = vec![false; self.n_vertices];
+
+        for root in 0..self.n_vertices {
+            if visited[root] {
+                continue;
+            }
+
+            let mut tree = Vec::new();
+            let mut queue = VecDeque::from([root]);
+            visited[root] = true;
+
+            while let Some(u) = queue.pop_front() {
+                for v in self.sorted_neighbors(u) {
+                    if !visited[v] {
+                        visited[v] = true;
+                        tree.push((u, v));
+                        queue.push_back(v);
+                    }
+                }
+            }
+
+            forest.push(tree);
+        }
+
+        forest
+    }
+
+    /// Count the internally vertex-disjoint paths between two distinct vertices
+    /// This is the number of independent routes between s and t: no two of them share a vertex
+    /// other than s and t, and a direct edge counts as one route.
+    pub fn vertex_disjoint_paths(&self, s: usize, t: usize) -> Result<usize, GraphError> {
+        for v in [s, t] {
+            if v >= self.n_vertices {
+                return Err(GraphError::VertexOutOfBounds(v));
+            }
+        }
+        if s == t {
+            return Err(GraphError::DuplicateVertex(s));
+        }
+
+        // Use the max-flow count rather than the greedy search, which can undercount
+        Ok(self.local_vertex_connectivity(s, t))
+    }
+
+    /// Find the maximum number of vertex-disjoint paths between vertices s and t
+    /// Common graph families are answered directly; otherwise this is an exact max-flow
+    fn find_vertex_disjoint_paths(&self, s: usize, t: usize) -> usize {
+        // Handle special cases for common graph types
+        // Complete graph with n vertices has n-1 vertex-disjoint paths between any two vertices
+        if self.is_complete() {
+            return self.n_vertices - 1;
+        }
+
+        // For cycle graphs, there are always 2 vertex-disjoint paths between any pair of vertices
+        if self.is_cycle() {
+            return 2;
+        }
+
+        // Path graphs have only 1 vertex-disjoint path between end vertices
+        if self.is_path()
+            && ((s == 0 && t == self.n_vertices - 1) || (t == 0 && s == self.n_vertices - 1))
+        {
+            return 1;
+        }
+
+        self.local_vertex_connectivity(s, t)
+    }
+
+    /// Check whether s and t are joined by at least k vertex-disjoint paths
+    /// A greedy search on a bitset copy of the graph usually finds k paths quickly; the exact
+    /// max-flow only runs when it falls short
+    fn has_vertex_disjoint_paths(&self, s: usize, t: usize, k: usize) -> bool {
+        // For adjacent vertices the direct edge is one path; the others are searched for
+        // in a copy of the graph without that edge
+        let adjacent = self.edges[s].contains(&t);
+        let direct_paths = usize::from(adjacent);
+        let limit = k.saturating_sub(direct_paths);
+
+        // Small and medium graphs use a bitset copy, which is cheap to search and to cut down
+        let greedy_paths = if self.n_vertices <= BITSET_MAX_VERTICES {
+            let mut working = BitsetAdjacency::from_graph(self);
+            if adjacent {
+                working.remove_edge(s, t);
+            }
+            count_greedy_disjoint_paths(&mut working, s, t, limit)
+        } else {
+            let mut working = HashSetAdjacency {
+                graph: self,
+                edges: self.edges.clone(),
+            };
+            if adjacent {
+                working.remove_edge(s, t);
+            }
+            count_greedy_disjoint_paths(&mut working, s, t, limit)
+        };
+
+        direct_paths + greedy_paths >= k || self.find_vertex_disjoint_paths(s, t) >= k
+    }
+
+    /// Helper function to find a path in a subgraph represented by the given edges
+    fn find_path_in_subgraph(&self, edges: &[HashSet<usize>], s: usize, t: usize) -> Option<Vec<usize>> {
+        use std::collections::VecDeque;
+
+        if s == t {
+            return Some(vec![s]);
+        }
+
+        let size = self.n_vertices.max(edges.len());
+        if s >= size || t >= size {
+            return None;
+        }
+
+        let mut parent = vec![None; size];
+        let mut queue = VecDeque::new();
+
+        parent[s] = Some(s);
+        queue.push_back(s);
+
+        while let Some(u) = queue.pop_front() {
+            // A vertex past the end of the adjacency slice has no neighbors
+            for &v in edges.get(u).into_iter().flatten() {
+                if parent[v].is_none() {
+                    parent[v] = Some(u);
+                    if v == t {
+                        // Reconstruct the path
+                        let mut path = vec![t];
+                        let mut current = t;
+                        while current != s {
+                            current = parent[current].unwrap();
+                            path.push(current);
+                        }
+
+                        path.reverse();
+                        return Some(path);
+                    }
+                    queue.push_back(v);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Find a path between vertices s and t using breadth-first search
+    /// Returns None if no path exists
+    fn find_path(&self, s: usize, t: usize) -> Option<Vec<usize>> {
+        self.find_path_in_subgraph(&self.edges, s, t)
+    }
+
+    /// Find a shortest (minimum-hop) path from s to t, including both endpoints
+    /// Returns None if either vertex is out of bounds or t is unreachable from s
+    pub fn shortest_path(&self, s: usize, t: usize) -> Option<Vec<usize>> {
+        if s >= self.n_vertices || t >= self.n_vertices {
+            return None;
+        }
+
+        // BFS discovers every vertex along a shortest path
+        self.find_path(s, t)
+    }
+
+    /// Get the number of edges on a shortest path from s to t
+    /// Returns None if either vertex is out of bounds or t is unreachable from s
+    pub fn distance(&self, s: usize, t: usize) -> Option<usize> {
+        if s >= self.n_vertices || t >= self.n_vertices {
+            return None;
+        }
+
+        self.bfs_distances(s)[t]
+    }
+
+    /// Get the neighbors of v in ascending order
+    fn sorted_neighbors(&self, v: usize) -> Vec<usize> {
+        let mut neighbors: Vec<usize> = self.edges[v].iter().cloned().collect();
+        neighbors.sort_unstable();
+        neighbors
+    }
+
+    /// Iterate over the vertices reachable from start in breadth-first order
+    /// Neighbors are visited in ascending order, so the order is deterministic
+    ///
+    /// # Panics
+    ///
+    /// Panics if start is out of bounds
+    pub fn bfs(&self, start: usize) -> impl Iterator<Item = usize> + '_ {
+        use std::collections::VecDeque;
+
+        assert!(start < self.n_vertices, "Start vertex {} out of bounds", start);
+
+        let mut visited = vec![false; self.n_vertices];
+        let mut queue = VecDeque::new();
+        visited[start] = true;
+        queue.push_back(start);
+
+        std::iter::from_fn(move || {
+            let u = queue.pop_front()?;
+            for v in self.sorted_neighbors(u) {
+                if !visited[v] {
+                    visited[v] = true;
+                    queue.push_back(v);
+                }
+            }
+            Some(u)
+        })
+    }
+
+    /// Iterate over the vertices reachable from start in depth-first (preorder) order
+    /// Neighbors are visited in ascending order, so the order is deterministic
+    ///
+    /// # Panics
+    ///
+    /// Panics if start is out of bounds
+    pub fn dfs(&self, start: usize) -> impl Iterator<Item = usize> + '_ {
+        assert!(start < self.n_vertices, "Start vertex {} out of bounds", start);
+
+        let mut visited = vec![false; self.n_vertices];
+        // Each stack entry holds a vertex's sorted neighbors and the next one to try
+        let mut stack: Vec<(Vec<usize>, usize)> = Vec::new();
+        let mut pending = Some(start);
+
+        std::iter::from_fn(move || {
+            if let Some(u) = pending.take() {
+                visited[u] = true;
+                stack.push((self.sorted_neighbors(u), 0));
+                return Some(u);
+            }
+
+            while let Some((neighbors, next)) = stack.last_mut() {
+                match neighbors.get(*next) {
+                    Some(&v) => {
+                        *next += 1;
+                        if !visited[v] {
+                            visited[v] = true;
+                            stack.push((self.sorted_neighbors(v), 0));
+                            return Some(v);
+                        }
+                    }
+                    None => {
+                        stack.pop();
+                    }
+                }
+            }
+
+            None
+        })
+    }
+
+    /// Compute the BFS distance from `source` to every vertex
+    /// Unreachable vertices are reported as None
+    fn bfs_distances(&self, source: usize) -> Vec<Option<usize>> {
+        use std::collections::VecDeque;
+
+        let mut distances = vec![None; self.n_vertices];
+        let mut queue = VecDeque::new();
+
+        distances[source] = Some(0);
+        queue.push_back(source);
+
+        while let Some(u) = queue.pop_front() {
+            let next = distances[u].unwrap() + 1;
+            for &v in &self.edges[u] {
+                if distances[v].is_none() {
+                    distances[v] = Some(next);
+                    queue.push_back(v);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Find the maximum number of edge-disjoint paths between vertices s and t
+    /// This is a unit-capacity max-flow (Dinic) on the graph itself
+    fn edge_disjoint_paths(&self, s: usize, t: usize) -> usize {
+        // Every undirected edge has capacity 1 in each direction
+        let mut network = FlowNetwork::new(self.n_vertices);
+        for (u, neighbors) in self.edges.iter().enumerate() {
+            for &v in neighbors {
+                network.add_arc(u, v, 1);
+            }
+        }
+
+        network.max_flow(s, t)
+    }
+
+    /// Find the maximum number of internally vertex-disjoint paths between distinct vertices s and t
+    /// This is a unit-capacity max-flow (Dinic) in the split graph, where every vertex v becomes
+    /// an arc `v_in → v_out` of capacity 1 and every edge `{u, v}` becomes the arcs
+    /// `u_out → v_in` and `v_out → u_in`. The flow runs from `s_out` to `t_in`, so a direct
+    /// edge counts as one path.
+    fn local_vertex_connectivity(&self, s: usize, t: usize) -> usize {
+        self.split_network(1).max_flow(2 * s + 1, 2 * t)
+    }
+
+    /// Build the split graph used for vertex-disjoint path flows
+    /// Node `2v` is `v_in` and node `2v + 1` is `v_out`; vertex arcs have capacity 1 and edge
+    /// arcs `edge_capacity`
+    fn split_network(&self, edge_capacity: u32) -> FlowNetwork {
+        let vertex_in = |v: usize| 2 * v;
+        let vertex_out = |v: usize| 2 * v + 1;
+
+        let mut network = FlowNetwork::new(2 * self.n_vertices);
+        for (u, neighbors) in self.edges.iter().enumerate() {
+            network.add_arc(vertex_in(u), vertex_out(u), 1);
+            for &v in neighbors {
+                network.add_arc(vertex_out(u), vertex_in(v), edge_capacity);
+            }
+        }
+
+        network
+    }
+
+    /// Find a smallest set of vertices whose removal disconnects s from t
+    ///
+    /// The set is read off a minimum cut of the split graph after a max-flow from s to t: it
+    /// holds the vertices whose `v_in` side is still reachable from s in the residual network
+    /// but whose `v_out` side is not, so its size equals the number of vertex-disjoint paths.
+    /// Of all minimum cuts this returns the one closest to s. The result is sorted, and is empty
+    /// if s and t are already disconnected or if they are adjacent, since no vertex set can
+    /// separate the endpoints of an edge.
+    ///
+    /// # Panics
+    ///
+    /// Panics if s or t is out of bounds or if s == t.
+    pub fn minimum_vertex_cut(&self, s: usize, t: usize) -> Vec<usize> {
+        assert!(s < self.n_vertices, "Vertex {} out of bounds", s);
+        assert!(t < self.n_vertices, "Vertex {} out of bounds", t);
+        assert_ne!(s, t, "The endpoints of a vertex cut must be distinct");
+
+        if self.edges[s].contains(&t) {
+            return Vec::new();
+        }
+
+        // Edge arcs can carry every path at once, so a minimum cut only uses vertex arcs
+        let mut network = self.split_network(self.n_vertices as u32);
+        network.max_flow(2 * s + 1, 2 * t);
+        let source_side = network.reachable_from(2 * s + 1);
+
+        (0..self.n_vertices)
+            .filter(|&v| source_side[2 * v] && !source_side[2 * v + 1])
+            .collect()
+    }
+
+    /// Compute the local vertex connectivity between every pair of vertices
+    /// Entry `[s][t]` is the maximum number of internally vertex-disjoint paths between s and t,
+    /// where a direct edge counts as one path. Diagonal entries are 0. The minimum over distinct
+    /// pairs is the vertex connectivity κ(G). This runs a max-flow per pair, so it is expensive
+    /// on large graphs.
+    pub fn vertex_connectivity_matrix(&self) -> Vec<Vec<usize>> {
+        use std::cmp::Ordering;
+
+        let n = self.n_vertices;
+
+        // upper[s][i] holds the entry for the pair (s, s + 1 + i)
+        let upper: Vec<Vec<usize>> = (0..n)
+            .map(|s| ((s + 1)..n).map(|t| self.local_vertex_connectivity(s, t)).collect())
+            .collect();
+
+        (0..n)
+            .map(|s| {
+                (0..n)
+                    .map(|t| match s.cmp(&t) {
+                        Ordering::Less => upper[s][t - s - 1],
+                        Ordering::Greater => upper[t][s - t - 1],
+                        Ordering::Equal => 0,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Calculate the edge connectivity λ(G)
+    /// This is the minimum number of edges whose removal disconnects the graph
+    pub fn edge_connectivity(&self) -> usize {
+        if self.n_vertices <= 1 || !self.is_connected() {
+            return 0;
+        }
+
+        // Every minimum edge cut separates vertex 0 from some other vertex
+        (1..self.n_vertices)
+            .map(|t| self.edge_disjoint_paths(0, t))
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Check if the graph is k-edge-connected: at least k edges must be removed to disconnect it
+    /// Every graph is 0-edge-connected; otherwise the graph needs at least 2 vertices
+    pub fn is_k_edge_connected(&self, k: usize) -> bool {
+        if k == 0 {
+            return true;
+        }
+        if self.n_vertices <= 1 || self.min_degree() < k || !self.is_connected() {
+            return false;
+        }
+
+        // Every edge cut separates vertex 0 from some other vertex
+        (1..self.n_vertices).all(|t| self.edge_disjoint_paths(0, t) >= k)
+    }
+
+    /// Get all vertices within `radius` hops of v, in increasing index order
+    fn ball(&self, v: usize, radius: usize) -> Vec<usize> {
+        self.bfs_distances(v)
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, d)| d.is_some_and(|d| d <= radius))
+            .map(|(u, _)| u)
+            .collect()
+    }
+
+    /// Build the subgraph induced by the given distinct, in-bounds vertices
+    /// Vertex `vertices[i]` becomes vertex i in the result
+    fn induced_subgraph_unchecked(&self, vertices: &[usize]) -> Graph {
+        let index: HashMap<usize, usize> = vertices
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| (v, i))
+            .collect();
+
+        let mut subgraph = Graph::new(vertices.len());
+        for (i, &u) in vertices.iter().enumerate() {
+            for v in &self.edges[u] {
+                if let Some(&j) = index.get(v) {
+                    if i < j {
+                        subgraph.add_edge(i, j).unwrap();
+                    }
+                }
+            }
+        }
+
+        subgraph
+    }
+
+    /// Get the complement graph on the same vertices
+    /// `(u, v)` is an edge of the complement iff u ≠ v and `(u, v)` is not an edge of this graph
+    pub fn complement(&self) -> Graph {
+        let mut complement = Graph::new(self.n_vertices);
+
+        for u in 0..self.n_vertices {
+            let neighbors = &self.edges[u];
+            for v in (u + 1)..self.n_vertices {
+                if !neighbors.contains(&v) {
+                    complement.add_edge(u, v).unwrap();
+                }
+            }
+        }
+
+        complement
+    }
+
+    /// Build the line graph, with one vertex per edge of this graph
+    /// Two line-graph vertices are adjacent iff the corresponding edges share an endpoint.
+    /// The returned vector maps each line-graph vertex back to its `(u, v)` edge with `u < v`,
+    /// in the order of `edge_list`.
+    pub fn line_graph(&self) -> (Graph, Vec<(usize, usize)>) {
+        let edges = self.edge_list();
+
+        // Indices of the edges meeting at each vertex
+        let mut incident = vec![Vec::new(); self.n_vertices];
+        for (i, &(u, v)) in edges.iter().enumerate() {
+            incident[u].push(i);
+            incident[v].push(i);
+        }
+
+        // Edges of a simple graph share at most one endpoint, so each pair is added once
+        let mut line = Graph::new(edges.len());
+        for at_vertex in &incident {
+            for (a, &i) in at_vertex.iter().enumerate() {
+                for &j in &at_vertex[a + 1..] {
+                    line.add_edge(i, j).unwrap();
+                }
+            }
+        }
+
+        (line, edges)
+    }
+
+    /// Build the Cartesian product G □ H of this graph G with `other`
+    ///
+    /// The pair `(a, b)` becomes vertex `a * other.vertex_count() + b`. Two pairs are adjacent
+    /// iff they agree in one coordinate and are adjacent in the other, so the product of two
+    /// paths is a grid and the product of two cycles is a torus. Edge weights are not carried over.
+    pub fn cartesian_product(&self, other: &Graph) -> Graph {
+        let m = other.n_vertices;
+        let mut product = Graph::new(self.n_vertices * m);
+
+        for (a, b) in self.edge_list() {
+            for x in 0..m {
+                product.add_edge(a * m + x, b * m + x).unwrap();
+            }
+        }
+        for (x, y) in other.edge_list() {
+            for a in 0..self.n_vertices {
+                product.add_edge(a * m + x, a * m + y).unwrap();
+            }
+        }
+
+        product
+    }
+
+    /// Count the edges with one endpoint in `a` and the other in `b`
+    ///
+    /// With disjoint sets this is the size of the cut between them. The sets may overlap: an
+    /// edge with both endpoints in both sets still counts once, so `edges_between(s, s)` is the
+    /// number of edges inside s. Repeated and out-of-bounds vertices are ignored.
+    pub fn edges_between(&self, a: &[usize], b: &[usize]) -> usize {
+        let mut in_a = vec![false; self.n_vertices];
+        let mut in_b = vec![false; self.n_vertices];
+        for &v in a.iter().filter(|&&v| v < self.n_vertices) {
+            in_a[v] = true;
+        }
+        for &v in b.iter().filter(|&&v| v < self.n_vertices) {
+            in_b[v] = true;
+        }
+
+        // An edge u -- v qualifies if u is in a and v in b, or the other way round
+        self.edge_list()
+            .into_iter()
+            .filter(|&(u, v)| (in_a[u] && in_b[v]) || (in_a[v] && in_b[u]))
+            .count()
+    }
+
+    /// Get the subgraph induced by the given vertices
+    ///
+    /// The result is relabeled so that `vertices[i]` becomes vertex i, and only edges between
+    /// selected vertices are kept. The returned mapping translates new indices back to the
+    /// original ones. Out-of-bounds or duplicate indices are rejected.
+    pub fn induced_subgraph(&self, vertices: &[usize]) -> Result<(Graph, Vec<usize>), GraphError> {
+        let mut seen = HashSet::new();
+        for &v in vertices {
+            if v >= self.n_vertices {
+                return Err(GraphError::VertexOutOfBounds(v));
+            }
+            if !seen.insert(v) {
+                return Err(GraphError::DuplicateVertex(v));
+            }
+        }
+
+        Ok((self.induced_subgraph_unchecked(vertices), vertices.to_vec()))
+    }
+
+    /// Get the subgraph induced by all vertices within `radius` hops of v
+    /// The vertices are renumbered in increasing order of their original index.
+    /// Returns an empty graph if v is out of bounds.
+    pub fn neighborhood_subgraph(&self, v: usize, radius: usize) -> Graph {
+        if v >= self.n_vertices {
+            return Graph::new(0);
+        }
+
+        self.induced_subgraph_unchecked(&self.ball(v, radius))
+    }
+
+    /// Calculate the Wiener index (sum of distances over all unordered vertex pairs)
+    /// Returns None if the graph is disconnected
+    pub fn wiener_index(&self) -> Option<usize> {
+        let mut sum = 0;
+
+        for s in 0..self.n_vertices {
+            let distances = self.bfs_distances(s);
+            for d in distances.iter().skip(s + 1) {
+                sum += (*d)?;
+            }
+        }
+
+        Some(sum)
+    }
+
+    /// Get the degree centrality of every vertex, its degree divided by n - 1
+    /// Returns all zeros for graphs with fewer than 2 vertices
+    pub fn degree_centrality(&self) -> Vec<f64> {
+        if self.n_vertices < 2 {
+            return vec![0.0; self.n_vertices];
+        }
+
+        let max_degree = (self.n_vertices - 1) as f64;
+        self.edges.iter().map(|neighbors| neighbors.len() as f64 / max_degree).collect()
+    }
+
+    /// Get the closeness centrality of every vertex, the reciprocal of its average distance
+    /// to the other vertices
+    /// In a disconnected graph only the vertices reachable from v are averaged over;
+    /// isolated vertices get 0.0
+    pub fn closeness_centrality(&self) -> Vec<f64> {
+        (0..self.n_vertices)
+            .map(|v| {
+                let reachable: Vec<usize> = self.bfs_distances(v)
+                    .into_iter()
+                    .flatten()
+                    .filter(|&d| d > 0)
+                    .collect();
+
+                if reachable.is_empty() {
+                    0.0
+                } else {
+                    reachable.len() as f64 / reachable.iter().sum::<usize>() as f64
+                }
+            })
+            .collect()
+    }
+
+    /// Build a spanning tree with many leaves (approximate)
+    /// Finding a maximum leaf spanning tree is NP-hard, so this uses a greedy heuristic:
+    /// the tree is grown from a maximum-degree vertex, and at each step the tree vertex
+    /// with the most neighbors outside the tree is expanded, attaching all of them as children.
+    /// Returns None if the graph is disconnected
+    pub fn max_leaf_spanning_tree_approx(&self) -> Option<Graph> {
+        if !self.is_connected() {
+            return None;
+        }
+
+        let mut tree = Graph::new(self.n_vertices);
+        if self.n_vertices == 0 {
+            return Some(tree);
+        }
+
+        let mut in_tree = vec![false; self.n_vertices];
+        let root = (0..self.n_vertices)
+            .max_by_key(|&v| (self.edges[v].len(), std::cmp::Reverse(v)))
+            .unwrap();
+        in_tree[root] = true;
+
+        // Tree vertices that have not been expanded yet
+        let mut frontier = vec![root];
+        let mut tree_size = 1;
+
+        while tree_size < self.n_vertices {
+            let (index, _) = frontier
+                .iter()
+                .enumerate()
+                .max_by_key(|&(_, &u)| {
+                    self.edges[u]
+                        .iter()
+                        .filter(|&&v| !in_tree[v])
+                        .count()
+                })
+                .unwrap();
+            let u = frontier.swap_remove(index);
+
+            let mut children: Vec<usize> = self.edges[u]
+                .iter()
+                .cloned()
+                .filter(|&v| !in_tree[v])
+                .collect();
+            children.sort_unstable();
+
+            for v in children {
+                in_tree[v] = true;
+                tree.add_edge(u, v).unwrap();
+                frontier.push(v);
+                tree_size += 1;
+            }
+        }
+
+        Some(tree)
+    }
+
+    /// Count the leaves of the DFS tree rooted at start
+    /// Neighbors are explored in ascending order, and only the component containing start is
+    /// covered. A count of 1 means the DFS tree is a single path from start, which is a
+    /// Hamiltonian path of that component. Returns 0 if start is out of bounds.
+    pub fn dfs_tree_leaf_count(&self, start: usize) -> usize {
+        if start >= self.n_vertices {
+            return 0;
+        }
+
+        let mut visited = vec![false; self.n_vertices];
+        visited[start] = true;
+
+        // Each stack entry holds a vertex, its sorted neighbors, the next neighbor to try,
+        // and whether it has gained a child in the tree
+        let mut stack = vec![(start, self.sorted_neighbors(start), 0, false)];
+        let mut leaves = 0;
+
+        while let Some((_, neighbors, next, has_child)) = stack.last_mut() {
+            if let Some(&v) = neighbors.get(*next) {
+                *next += 1;
+                if !visited[v] {
+                    visited[v] = true;
+                    *has_child = true;
+                    stack.push((v, self.sorted_neighbors(v), 0, false));
+                }
+            } else {
+                if !*has_child {
+                    leaves += 1;
+                }
+                stack.pop();
+            }
+        }
+
+        leaves
+    }
+
+    /// Build a spanning tree with a small maximum degree (approximate)
+    ///
+    /// Finding a minimum degree spanning tree is NP-hard, so this is a local-search heuristic.
+    /// It starts from a BFS tree and repeatedly looks for a non-tree edge `(u, v)` whose tree
+    /// cycle passes through a maximum-degree vertex w while u and v have degree at most
+    /// `max - 2`; swapping that edge in for a cycle edge at w lowers w's degree without creating
+    /// a new maximum. It stops once no such swap exists. Returns None if the graph is disconnected.
+    pub fn min_degree_spanning_tree_approx(&self) -> Option<Graph> {
+        use std::collections::VecDeque;
+
+        if !self.is_connected() {
+            return None;
+        }
+
+        let n = self.n_vertices;
+        let mut tree: Vec<HashSet<usize>> = vec![HashSet::new(); n];
+
+        // Start from a BFS tree rooted at vertex 0
+        if n > 0 {
+            let mut visited = vec![false; n];
+            let mut queue = VecDeque::new();
+            visited[0] = true;
+            queue.push_back(0);
+
+            while let Some(u) = queue.pop_front() {
+                let mut neighbors: Vec<usize> = self.edges[u].iter().cloned().collect();
+                neighbors.sort_unstable();
+                for v in neighbors {
+                    if !visited[v] {
+                        visited[v] = true;
+                        tree[u].insert(v);
+                        tree[v].insert(u);
+                        queue.push_back(v);
+                    }
+                }
+            }
+        }
+
+        // Path between u and v inside the current tree
+        let tree_path = |tree: &[HashSet<usize>], u: usize, v: usize| -> Vec<usize> {
+            let mut parent = vec![None; n];
+            let mut queue = VecDeque::new();
+            parent[u] = Some(u);
+            queue.push_back(u);
+
+            while let Some(x) = queue.pop_front() {
+                if x == v {
+                    break;
+                }
+                for &y in &tree[x] {
+                    if parent[y].is_none() {
+                        parent[y] = Some(x);
+                        queue.push_back(y);
+                    }
+                }
+            }
+
+            let mut path = vec![v];
+            let mut current = v;
+            while current != u {
+                current = parent[current].unwrap();
+                path.push(current);
+            }
+            path
+        };
+
+        let graph_edges = self.edge_list();
+
+        loop {
+            let max_degree = tree.iter().map(|t| t.len()).max().unwrap_or(0);
+            if max_degree <= 2 {
+                break;
+            }
+
+            let mut improved = false;
+
+            'search: for w in (0..n).filter(|&w| tree[w].len() == max_degree) {
+                for &(u, v) in &graph_edges {
+                    if tree[u].contains(&v)
+                        || tree[u].len() + 2 > max_degree
+                        || tree[v].len() + 2 > max_degree
+                    {
+                        continue;
+                    }
+
+                    let path = tree_path(&tree, u, v);
+                    if let Some(i) = path.iter().position(|&x| x == w) {
+                        // w has degree max_degree > deg(u), deg(v), so it is internal to the path
+                        let x = path[i - 1];
+                        tree[w].remove(&x);
+                        tree[x].remove(&w);
+                        tree[u].insert(v);
+                        tree[v].insert(u);
+                        improved = true;
+                        break 'search;
+                    }
+                }
+            }
+
+            if !improved {
+                break;
+            }
+        }
+
+        let mut result = Graph::new(n);
+        for (u, neighbors) in tree.iter().enumerate() {
+            for &v in neighbors {
+                if u < v {
+                    result.add_edge(u, v).unwrap();
+                }
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Check whether this graph is isomorphic to `other`
+    ///
+    /// Cheap invariants (vertex and edge counts, the degree sequence and both Zagreb
+    /// indices) rule out most non-isomorphic pairs before a backtracking search that
+    /// extends a partial vertex mapping while preserving adjacency. The search is
+    /// exponential in the worst case, e.g. for large regular graphs that share every invariant.
+    pub fn is_isomorphic(&self, other: &Graph) -> bool {
+        if self.n_vertices != other.n_vertices
+            || self.n_edges != other.n_edges
+            || self.first_zagreb_index() != other.first_zagreb_index()
+            || self.second_zagreb_index() != other.second_zagreb_index()
+        {
+            return false;
+        }
+
+        // find_isomorphism also compares degree sequences before backtracking
+        self.find_isomorphism(other, None).is_some()
+    }
+
+    /// Search for an isomorphism from this graph onto `other` by backtracking
+    ///
+    /// If `fixed` is `Some((a, b))`, only isomorphisms mapping a to b are considered.
+    /// Returns the vertex mapping (`mapping[v]` is the image of v) if one exists.
+    /// This is exponential in the worst case.
+    fn find_isomorphism(&self, other: &Graph, fixed: Option<(usize, usize)>) -> Option<Vec<usize>> {
+        let n = self.n_vertices;
+        if n != other.n_vertices || self.n_edges != other.n_edges {
+            return None;
+        }
+
+        let mut self_degrees: Vec<usize> = (0..n).map(|v| self.edges[v].len()).collect();
+        let mut other_degrees: Vec<usize> = (0..n).map(|v| other.edges[v].len()).collect();
+        let degrees = self_degrees.clone();
+        self_degrees.sort_unstable();
+        other_degrees.sort_unstable();
+        if self_degrees != other_degrees {
+            return None;
+        }
+
+        let mut mapping = vec![usize::MAX; n];
+        let mut used = vec![false; n];
+
+        // Visit vertices in BFS order so most vertices have an already-mapped neighbor
+        let mut order = Vec::with_capacity(n);
+        let mut queued = vec![false; n];
+        let starts = fixed.map(|(a, _)| a).into_iter().chain(0..n);
+        for start in starts {
+            if queued[start] {
+                continue;
+            }
+            queued[start] = true;
+            let mut next = order.len();
+            order.push(start);
+            while next < order.len() {
+                let u = order[next];
+                next += 1;
+                for &v in &self.edges[u] {
+                    if !queued[v] {
+                        queued[v] = true;
+                        order.push(v);
+                    }
+                }
+            }
+        }
+
+        if let Some((a, b)) = fixed {
+            if a >= n || b >= n || degrees[a] != other.edges[b].len() {
+                return None;
+            }
+            mapping[a] = b;
+            used[b] = true;
+        }
+
+        if self.extend_isomorphism(other, &order, 0, &mut mapping, &mut used) {
+            Some(mapping)
+        } else {
+            None
+        }
+    }
+
+    /// Backtracking step for `find_isomorphism`
+    fn extend_isomorphism(
+        &self,
+        other: &Graph,
+        order: &[usize],
+        position: usize,
+        mapping: &mut [usize],
+        used: &mut [bool],
+    ) -> bool {
+        if position == order.len() {
+            return true;
+        }
+
+        let u = order[position];
+        if mapping[u] != usize::MAX {
+            return self.extend_isomorphism(other, order, position + 1, mapping, used);
+        }
+
+        let u_neighbors = &self.edges[u];
+
+        // If u has a mapped neighbor, its image must be a neighbor of that neighbor's image
+        let candidates: Vec<usize> = match u_neighbors.iter().find(|&&w| mapping[w] != usize::MAX) {
+            Some(&w) => other.edges[mapping[w]].iter().cloned().collect(),
+            None => (0..other.n_vertices).collect(),
+        };
+
+        for c in candidates {
+            if used[c] || other.edges[c].len() != u_neighbors.len() {
+                continue;
+            }
+
+            // Adjacency to every mapped vertex must be preserved
+            let c_neighbors = &other.edges[c];
+            let consistent = order[..position]
+                .iter()
+                .chain(order[position + 1..].iter())
+                .filter(|&&w| mapping[w] != usize::MAX)
+                .all(|&w| u_neighbors.contains(&w) == c_neighbors.contains(&mapping[w]));
+            if !consistent {
+                continue;
+            }
+
+            mapping[u] = c;
+            used[c] = true;
+            if self.extend_isomorphism(other, order, position + 1, mapping, used) {
+                return true;
+            }
+            mapping[u] = usize::MAX;
+            used[c] = false;
+        }
+
+        false
+    }
+
+    /// Check whether the automorphism group acts transitively on the vertices
+    ///
+    /// Every vertex must be mappable onto every other by some automorphism. The automorphism
+    /// search is exponential in the worst case, so None is returned for graphs with more than
+    /// `max_vertices` vertices.
+    pub fn is_vertex_transitive(&self, max_vertices: usize) -> Option<bool> {
+        if self.n_vertices > max_vertices {
+            return None;
+        }
+
+        // Automorphisms preserve degrees, so a vertex-transitive graph is regular
+        if self.min_degree() != self.max_degree() {
+            return Some(false);
+        }
+
+        // The orbit of vertex 0 must contain every vertex
+        Some((1..self.n_vertices).all(|v| self.find_isomorphism(self, Some((0, v))).is_some()))
+    }
+
+    /// Find a large cut (approximate maximum cut)
+    ///
+    /// Vertices are first placed greedily on the side opposite most of their already-placed
+    /// neighbors, then single vertices are moved between sides while that strictly increases
+    /// the cut. At a local optimum every vertex has at least half its neighbors across the cut,
+    /// so the result is at least half the edges and thus a 0.5-approximation. Returns the number
+    /// of edges crossing the cut, which equals the edge count minus the edges deleted to leave
+    /// the two sides independent.
+    pub fn max_cut_approx(&self) -> usize {
+        let n = self.n_vertices;
+        let mut side: Vec<Option<bool>> = vec![None; n];
+
+        for v in 0..n {
+            let (mut same_true, mut same_false) = (0, 0);
+            for &u in &self.edges[v] {
+                match side[u] {
+                    Some(true) => same_true += 1,
+                    Some(false) => same_false += 1,
+                    None => {}
+                }
+            }
+            side[v] = Some(same_true <= same_false);
+        }
+
+        let mut side: Vec<bool> = side.into_iter().map(|s| s.unwrap()).collect();
+
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for v in 0..n {
+                let same = self.edges[v]
+                    .iter()
+                    .filter(|&&u| side[u] == side[v])
+                    .count();
+                let across = self.edges[v].len() - same;
+                if same > across {
+                    side[v] = !side[v];
+                    improved = true;
+                }
+            }
+        }
+
+        self.edge_list()
+            .into_iter()
+            .filter(|&(u, v)| side[u] != side[v])
+            .count()
+    }
+
+    /// Try to find a Hamiltonian path with a fast greedy heuristic (not exact)
+    ///
+    /// The path starts at a minimum-degree vertex and is extended from its end to the unvisited
+    /// neighbor with the fewest unvisited neighbors. When stuck, a Pósa rotation is tried: if the
+    /// end is adjacent to some path vertex `p[i]`, reversing the tail after `p[i]` gives a new end
+    /// `p[i + 1]` that may be extendable. The path is also grown from its start the same way.
+    /// Each vertex is tried as the start of the rotation search a bounded number of times, so the
+    /// cost stays polynomial. Returns None if the heuristic fails, which does not prove that no
+    /// Hamiltonian path exists.
+    pub fn greedy_hamiltonian_path(&self) -> Option<Vec<usize>> {
+        let n = self.n_vertices;
+        if n == 0 {
+            return None;
+        }
+
+        let start = (0..n).min_by_key(|&v| self.edges[v].len()).unwrap();
+        let mut path = vec![start];
+        let mut on_path = vec![false; n];
+        on_path[start] = true;
+
+        // Bound the number of rotations to keep the heuristic polynomial
+        let mut rotations_left = n * n;
+
+        while path.len() < n {
+            if self.extend_path_end(&mut path, &mut on_path) {
+                continue;
+            }
+
+            // Try growing from the other end
+            path.reverse();
+            if self.extend_path_end(&mut path, &mut on_path) {
+                continue;
+            }
+
+            // Stuck at both ends: rotate to obtain a new endpoint that has unvisited neighbors
+            let end = *path.last().unwrap();
+            let end_neighbors = &self.edges[end];
+            let pivot = (0..path.len().saturating_sub(2)).find(|&i| {
+                end_neighbors.contains(&path[i])
+                    && self.edges[path[i + 1]].iter().any(|&w| !on_path[w])
+            });
+
+            match pivot {
+                Some(i) if rotations_left > 0 => {
+                    rotations_left -= 1;
+                    path[i + 1..].reverse();
+                }
+                _ => return None,
+            }
+        }
+
+        Some(path)
+    }
+
+    /// Extend a path from its last vertex to the unvisited neighbor with the fewest unvisited
+    /// neighbors, returning whether an extension was made
+    fn extend_path_end(&self, path: &mut Vec<usize>, on_path: &mut [bool]) -> bool {
+        let end = *path.last().unwrap();
+        let next = self.edges[end]
+            .iter()
+            .filter(|&&v| !on_path[v])
+            .min_by_key(|&&v| {
+                let unvisited = self.edges[v].iter().filter(|&&w| !on_path[w]).count();
+                (unvisited, v)
+            });
+
+        match next {
+            Some(&v) => {
+                on_path[v] = true;
+                path.push(v);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Check if the graph has an Eulerian circuit, a closed walk using every edge exactly once
+    /// This holds iff the graph is connected and every vertex has even degree
+    pub fn is_eulerian(&self) -> bool {
+        self.n_vertices > 0
+            && self.is_connected()
+            && self.edges.iter().all(|neighbors| neighbors.len() % 2 == 0)
+    }
+
+    /// Check if the graph has an Eulerian path, a walk using every edge exactly once
+    /// This holds iff the graph is connected and has exactly 0 or 2 vertices of odd degree
+    pub fn has_eulerian_path(&self) -> bool {
+        let odd_count = self.edges.iter().filter(|neighbors| neighbors.len() % 2 == 1).count();
+        self.n_vertices > 0 && self.is_connected() && (odd_count == 0 || odd_count == 2)
+    }
+
+    /// Find an Eulerian circuit with Hierholzer's algorithm
+    /// The circuit is returned as the sequence of visited vertices, starting and ending at
+    /// vertex 0, so consecutive entries are the traversed edges.
+    /// Returns None if the graph is not Eulerian.
+    pub fn eulerian_circuit(&self) -> Option<Vec<usize>> {
+        if !self.is_eulerian() {
+            return None;
+        }
+
+        Some(self.hierholzer(0))
+    }
+
+    /// Find an Eulerian path with Hierholzer's algorithm
+    /// The path starts at the lowest odd-degree vertex if there is one, or at vertex 0 otherwise,
+    /// in which case it is a circuit.
+    /// Returns None if the graph has no Eulerian path.
+    pub fn eulerian_path(&self) -> Option<Vec<usize>> {
+        if !self.has_eulerian_path() {
+            return None;
+        }
+
+        let start = (0..self.n_vertices)
+            .find(|&v| self.edges[v].len() % 2 == 1)
+            .unwrap_or(0);
+
+        Some(self.hierholzer(start))
+    }
+
+    /// Walk every edge once from `start`, splicing in detours until no unused edge is left
+    /// The caller must ensure that an Eulerian walk from `start` exists
+    fn hierholzer(&self, start: usize) -> Vec<usize> {
+        let neighbors: Vec<Vec<usize>> = (0..self.n_vertices)
+            .map(|v| self.sorted_neighbors(v))
+            .collect();
+        let mut next = vec![0; self.n_vertices];
+        let mut used: HashSet<(usize, usize)> = HashSet::new();
+
+        let mut stack = vec![start];
+        let mut walk = Vec::with_capacity(self.n_edges + 1);
+
+        while let Some(&u) = stack.last() {
+            // Skip edges already traversed from the other side
+            while next[u] < neighbors[u].len()
+                && used.contains(&(u.min(neighbors[u][next[u]]), u.max(neighbors[u][next[u]])))
+            {
+                next[u] += 1;
+            }
+
+            match neighbors[u].get(next[u]) {
+                Some(&v) => {
+                    used.insert((u.min(v), u.max(v)));
+                    stack.push(v);
+                }
+                None => {
+                    walk.push(u);
+                    stack.pop();
+                }
+            }
+        }
+
+        walk.reverse();
+        walk
+    }
+
+    /// Find a Hamiltonian cycle using backtracking
+    /// The cycle is returned as a vertex order starting at vertex 0, without repeating the start.
+    /// Returns None if the graph has fewer than 3 vertices or no Hamiltonian cycle exists.
+    /// The search is exponential in the worst case, so it is only practical for small graphs.
+    pub fn hamiltonian_cycle(&self) -> Option<Vec<usize>> {
+        if self.n_vertices < 3 {
+            return None;
+        }
+
+        // Every Hamiltonian cycle passes through 0, so it closes with an edge from a neighbor of 0
+        let mut closing: Vec<usize> = self.edges[0].iter().cloned().collect();
+        closing.sort_unstable();
+
+        closing
+            .into_iter()
+            .find_map(|t| self.hamiltonian_path_between(0, t))
+    }
+
+    /// Find a Hamiltonian path that starts at s and ends at t using backtracking
+    /// Returns None if no such path exists or either vertex is out of bounds.
+    /// The search is exponential in the worst case, so it is only practical for small graphs.
+    pub fn hamiltonian_path_between(&self, s: usize, t: usize) -> Option<Vec<usize>> {
+        if s >= self.n_vertices || t >= self.n_vertices {
+            return None;
+        }
+
+        if s == t {
+            return if self.n_vertices == 1 { Some(vec![s]) } else { None };
+        }
+
+        let mut visited = vec![false; self.n_vertices];
+        let mut path = vec![s];
+        visited[s] = true;
+
+        if self.extend_hamiltonian_path(t, &mut visited, &mut path) {
+            Some(path)
+        } else {
+            None
+        }
+    }
+
+    /// Backtracking step for `hamiltonian_path_between`
+    fn extend_hamiltonian_path(&self, t: usize, visited: &mut [bool], path: &mut Vec<usize>) -> bool {
+        let u = *path.last().unwrap();
+        if path.len() == self.n_vertices {
+            return u == t;
+        }
+
+        // The target may only be visited last
+        if u == t {
+            return false;
+        }
+
+        let mut neighbors: Vec<usize> = self.edges[u]
+            .iter()
+            .cloned()
+            .filter(|&v| !visited[v])
+            .collect();
+        neighbors.sort_unstable();
+
+        for v in neighbors {
+            visited[v] = true;
+            path.push(v);
+
+            if self.extend_hamiltonian_path(t, visited, path) {
+                return true;
+            }
+
+            path.pop();
+            visited[v] = false;
+        }
+
+        false
+    }
+
+    /// Find a maximum clique using Bron–Kerbosch with pivoting
+    /// Returns the vertices of one maximum clique in increasing order. The clique number of the
+    /// complement is the independence number, so this also yields an exact independence number.
+    /// The search is exponential in the worst case.
+    pub fn max_clique(&self) -> Vec<usize> {
+        let mut best = Vec::new();
+        let mut clique = Vec::new();
+        let candidates: HashSet<usize> = (0..self.n_vertices).collect();
+
+        self.bron_kerbosch(&mut clique, candidates, HashSet::new(), &mut best);
+
+        best.sort_unstable();
+        best
+    }
+
+    /// Recursive step of `max_clique`
+    /// `candidates` can still extend `clique`, while `excluded` have already been explored
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: HashSet<usize>,
+        mut excluded: HashSet<usize>,
+        best: &mut Vec<usize>,
+    ) {
+        if candidates.is_empty() {
+            if excluded.is_empty() && clique.len() > best.len() {
+                *best = clique.clone();
+            }
+            return;
+        }
+
+        // Even taking every candidate could not beat the best clique found so far
+        if clique.len() + candidates.len() <= best.len() {
+            return;
+        }
+
+        // Pivot on the vertex covering the most candidates; only its non-neighbors need branching
+        let pivot = candidates
+            .union(&excluded)
+            .max_by_key(|&&u| {
+                let neighbors = &self.edges[u];
+                (candidates.iter().filter(|v| neighbors.contains(v)).count(), std::cmp::Reverse(u))
+            })
+            .copied()
+            .unwrap();
+        let pivot_neighbors = &self.edges[pivot];
+
+        let mut branches: Vec<usize> = candidates
+            .iter()
+            .cloned()
+            .filter(|v| !pivot_neighbors.contains(v))
+            .collect();
+        branches.sort_unstable();
+
+        for v in branches {
+            let neighbors = &self.edges[v];
+
+            clique.push(v);
+            self.bron_kerbosch(
+                clique,
+                candidates.intersection(neighbors).cloned().collect(),
+                excluded.intersection(neighbors).cloned().collect(),
+                best,
+            );
+            clique.pop();
+
+            candidates.remove(&v);
+            excluded.insert(v);
+        }
+    }
+
+    /// Calculate independence number (approximate)
+    /// The size of `independent_set_approx`
+    pub fn independence_number_approx(&self) -> usize {
+        self.independent_set_approx().len()
+    }
+
+    /// Find a large independent set greedily
+    ///
+    /// Finding a maximum independent set is NP-hard, so this is a greedy approximation: it
+    /// repeatedly takes a vertex of minimum degree in the remaining graph and drops its
+    /// neighbors. Isolated vertices have degree 0, so they are always taken first, and a graph
+    /// with no vertices gives an empty set. Ties are broken by smallest vertex index rather than
+    /// by `HashSet` iteration order, so the result is the same across calls, clones and
+    /// machines. The vertices are returned in the order they were chosen.
+    pub fn independent_set_approx(&self) -> Vec<usize> {
+        // Degrees within the remaining graph, kept up to date as vertices are removed
+        let mut residual_degree: Vec<usize> =
+            (0..self.n_vertices).map(|v| self.degree_unchecked(v)).collect();
+        let mut removed = vec![false; self.n_vertices];
+        let mut independent_set = Vec::new();
+
+        // Select vertex with minimum degree in the remaining graph, the smallest index on ties
+        while let Some(min_degree_vertex) = (0..self.n_vertices)
+            .filter(|&v| !removed[v])
+            .min_by_key(|&v| (residual_degree[v], v))
+        {
+            // Add it to independent set
+            independent_set.push(min_degree_vertex);
+
+            // Remove it and its neighbors from consideration
+            let mut dropped = vec![min_degree_vertex];
+            dropped.extend(self.edges[min_degree_vertex].iter().filter(|&&u| !removed[u]));
+            for &u in &dropped {
+                removed[u] = true;
+            }
+            for &u in &dropped {
+                for &w in &self.edges[u] {
+                    if !removed[w] {
+                        residual_degree[w] -= 1;
+                    }
+                }
+            }
+        }
+
+        independent_set
+    }
+
+    /// Calculate the exact independence number α(G)
+    /// A maximum independent set is a maximum clique of the complement, so this runs
+    /// `max_clique` on the complement. Unlike `independence_number_approx` it never undercounts,
+    /// but the search is exponential in the worst case.
+    pub fn independence_number_exact(&self) -> usize {
+        self.complement().max_clique().len()
+    }
+
+    /// Find a small dominating set greedily
+    ///
+    /// Every vertex ends up in the set or adjacent to a member. Finding a minimum dominating set
+    /// is NP-hard, so this uses the greedy max-coverage heuristic: repeatedly take the vertex
+    /// whose closed neighborhood contains the most not-yet-dominated vertices, the smallest
+    /// index on ties. The result is at most 1 + ln(Δ + 1) times the optimum. The vertices are
+    /// returned in the order they were chosen.
+    pub fn dominating_set_approx(&self) -> Vec<usize> {
+        let mut dominated = vec![false; self.n_vertices];
+        let mut remaining = self.n_vertices;
+        let mut dominating_set = Vec::new();
+
+        while remaining > 0 {
+            let gain = |v: usize| {
+                usize::from(!dominated[v])
+                    + self.edges[v].iter().filter(|&&u| !dominated[u]).count()
+            };
+            let best = (0..self.n_vertices)
+                .max_by_key(|&v| (gain(v), std::cmp::Reverse(v)))
+                .unwrap();
+
+            dominating_set.push(best);
+            for u in std::iter::once(best).chain(self.edges[best].iter().cloned()) {
+                if !dominated[u] {
+                    dominated[u] = true;
+                    remaining -= 1;
+                }
+            }
+        }
+
+        dominating_set
+    }
+
+    /// Find a vertex cover within twice the minimum size
+    ///
+    /// Every edge has at least one endpoint in the set. The edges are scanned in `edge_list`
+    /// order to build a maximal matching, and both endpoints of each matched edge are taken.
+    /// Any cover must contain an endpoint of every matched edge, so the result is at most twice
+    /// the optimum. The vertices are returned in the order they were chosen.
+    pub fn vertex_cover_approx(&self) -> Vec<usize> {
+        let mut covered = vec![false; self.n_vertices];
+        let mut cover = Vec::new();
+
+        for (u, v) in self.edge_list() {
+            if !covered[u] && !covered[v] {
+                covered[u] = true;
+                covered[v] = true;
+                cover.push(u);
+                cover.push(v);
+            }
+        }
+
+        cover
+    }
+
+    /// Check if the graph is likely Hamiltonian, using the fast connectivity approximation
+    /// Equivalent to `is_likely_hamiltonian_with(false)`
+    pub fn is_likely_hamiltonian(&self) -> bool {
+        self.is_likely_hamiltonian_with(false)
+    }
+
+    /// Check if the graph is likely Hamiltonian using Theorem 1 from the paper and known graph properties
+    ///
+    /// # Arguments
+    ///
+    /// * `use_exact_connectivity` - Whether to use exact connectivity checking (slower but more accurate)
+    pub fn is_likely_hamiltonian_with(&self, use_exact_connectivity: bool) -> bool {
+        // We need at least 3 vertices for a Hamiltonian cycle
+        if self.n_vertices < 3 {
+            return false;
+        }
+
+        // Known case: Complete graphs with n ≥ 3 are always Hamiltonian
+        if self.is_complete() {
+            return true;
+        }
+
+        // Known case: Cycle graphs are Hamiltonian by definition
+        if self.is_cycle() {
+            return true;
+        }
+
+        // Special case: Stars with n > 3 are not Hamiltonian
+        if self.is_star() && self.n_vertices > 3 {
+            return false;
+        }
+
+        // Special case: The Petersen graph is known to be non-Hamiltonian
+        if self.is_petersen() {
+            return false;
+        }
+
+        // Check k-connectivity first (k ≥ 2)
+        let k = 2;
+        if !self.is_k_connected_with(k, use_exact_connectivity) {
+            return false;
+        }
+
+        // Dirac's theorem: If minimum degree ≥ n/2, the graph is Hamiltonian
+        if self.satisfies_dirac() {
+            return true;
+        }
+
+        // Theorem 1 excludes K_{k,k+1}, which reaches the threshold but has no Hamiltonian cycle
+        if self.is_complete_bipartite(k, k + 1) {
+            return false;
+        }
+
+        // Apply Theorem 1 from the paper
+        self.first_zagreb_index() >= self.hamiltonian_threshold(k)
+    }
+
+    /// Check if the graph is the complete bipartite graph K_{a,b}
+    fn is_complete_bipartite(&self, a: usize, b: usize) -> bool {
+        if self.n_vertices != a + b || self.n_edges != a * b {
+            return false;
+        }
+
+        // With a·b edges between sides of sizes a and b, every cross pair is an edge
+        match self.bipartition() {
+            Some((first, second)) => {
+                (first.len(), second.len()) == (a, b) || (first.len(), second.len()) == (b, a)
+            }
+            None => false,
+        }
+    }
+
+    /// Check Dirac's condition: n ≥ 3 and every vertex has degree at least n/2
+    /// Graphs satisfying it are Hamiltonian
+    pub fn satisfies_dirac(&self) -> bool {
+        self.n_vertices >= 3 && 2 * self.min_degree() >= self.n_vertices
+    }
+
+    /// Check Ore's condition: n ≥ 3 and d(u) + d(v) ≥ n for every non-adjacent pair u, v
+    /// Graphs satisfying it are Hamiltonian; Dirac's condition is the special case
+    pub fn satisfies_ore(&self) -> bool {
+        if self.n_vertices < 3 {
+            return false;
+        }
+
+        (0..self.n_vertices).all(|u| {
+            ((u + 1)..self.n_vertices).all(|v| {
+                self.edges[u].contains(&v)
+                    || self.edges[u].len() + self.edges[v].len() >= self.n_vertices
+            })
+        })
+    }
+
+    /// Check if the graph is likely traceable, using the fast connectivity approximation
+    /// Equivalent to `is_likely_traceable_with(false)`
+    pub fn is_likely_traceable(&self) -> bool {
+        self.is_likely_traceable_with(false)
+    }
+
+    /// Check if the graph is likely traceable using Theorem 2 from the paper and known graph properties
+    ///
+    /// # Arguments
+    ///
+    /// * `use_exact_connectivity` - Whether to use exact connectivity checking (slower but more accurate)
+    pub fn is_likely_traceable_with(&self, use_exact_connectivity: bool) -> bool {
+        // We need at least 2 vertices for a Hamiltonian path
+        if self.n_vertices < 2 {
+            return false;
+        }
+
+        // Known case: Any Hamiltonian graph is also traceable
+        if self.is_likely_hamiltonian_with(use_exact_connectivity) {
+            return true;
+        }
+
+        // Known case: Complete graphs are always traceable
+        if self.is_complete() {
+            return true;
+        }
+
+        // Known case: Path graphs are traceable by definition
+        if self.is_path() {
+            return true;
+        }
+
+        // Known case: Star graphs are traceable
+        if self.is_star() {
+            return true;
+        }
+
+        // Special case: The Petersen graph is known to be traceable
+        if self.is_petersen() {
+            return true;
+        }
+
+        // Check k-connectivity first (k ≥ 1)
+        let k = 1;
+        if !self.is_k_connected_with(k, use_exact_connectivity) {
+            return false;
+        }
+
+        // Dirac-like condition for traceability: If minimum degree ≥ (n-1)/2, the graph is traceable
+        if self.min_degree() >= (self.n_vertices - 1) / 2 {
+            return true;
+        }
+
+        // The paper specifies n ≥ 9 for Theorem 2
+        if self.n_vertices < 9 {
+            // For smaller graphs, we'll use a simpler criterion
+            return self.min_degree() >= (self.n_vertices - 1) / 2;
+        }
+
+        // Apply Theorem 2 from the paper
+        self.first_zagreb_index() >= self.traceable_threshold(k)
+    }
+
+    /// Zagreb index threshold from Theorem 1 of the paper
+    /// A k-connected graph (k ≥ 2) whose first Zagreb index reaches this value is Hamiltonian
+    /// or is K_{k,k+1}
+    fn hamiltonian_threshold(&self, k: usize) -> usize {
+        self.zagreb_threshold(k + 1)
+    }
+
+    /// Zagreb index threshold from Theorem 2 of the paper
+    /// A k-connected graph (k ≥ 1, n ≥ 9) whose first Zagreb index reaches this value is
+    /// traceable or is K_{k,k+2}
+    fn traceable_threshold(&self, k: usize) -> usize {
+        self.zagreb_threshold(k + 2)
+    }
+
+    /// Shared form of the Theorem 1 and 2 thresholds with `j` = k + 1 or k + 2:
+    /// (n - j)·Δ² + e²/j + (√(n - j) - √δ)²·e
+    /// The real-valued bound is rounded up, since the integer Zagreb index reaches it
+    /// exactly when it reaches its ceiling; rounding down would accept graphs below it.
+    fn zagreb_threshold(&self, j: usize) -> usize {
+        let delta = self.min_degree() as f64;
+        let delta_max = self.max_degree() as f64;
+        let rest = self.n_vertices.saturating_sub(j) as f64;
+        let e = self.n_edges as f64;
+
+        let part1 = rest * delta_max * delta_max;
+        let part2 = e * e / j as f64;
+        let part3 = rest.sqrt() - delta.sqrt();
+
+        (part1 + part2 + part3 * part3 * e).ceil() as usize
+    }
+
+    /// Evaluate all three theorems from the paper in one call
+    ///
+    /// Theorem 1 is evaluated with k = 2 and Theorem 2 with k = 1, matching
+    /// `is_likely_hamiltonian` and `is_likely_traceable`. The `*_met` fields only compare the
+    /// Zagreb index against the threshold; the theorems additionally require k-connectivity.
+    pub fn paper_theorem_report(&self) -> TheoremReport {
+        let zagreb_index = self.first_zagreb_index();
+        let hamiltonian_threshold = self.hamiltonian_threshold(2);
+        let traceable_threshold = self.traceable_threshold(1);
+
+        TheoremReport {
+            zagreb_index,
+            hamiltonian_threshold,
+            hamiltonian_threshold_met: zagreb_index >= hamiltonian_threshold,
+            traceable_threshold,
+            traceable_threshold_met: zagreb_index >= traceable_threshold,
+            zagreb_upper_bound: self.zagreb_upper_bound(),
+        }
     }
 
     /// Check if the graph is a complete graph (every vertex is connected to every other vertex)
-    fn is_complete(&self) -> bool {
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zagreb_lib::Graph;
+    ///
+    /// assert!(Graph::complete(4).is_complete());
+    /// ```
+    pub fn is_complete(&self) -> bool {
         // A graph is complete if every vertex has degree n-1 (connected to all other vertices)
         if self.n_vertices <= 1 {
             return true; // A single vertex or empty graph is trivially complete
         }
 
-        // Check that every vertex has the same degree (n-1)
-        let expected_degree = self.n_vertices - 1;
+        // Check that every vertex has the same degree (n-1)
+        let expected_degree = self.n_vertices - 1;
+
+        for v in 0..self.n_vertices {
+            if self.edges[v].len() != expected_degree {
+                return false;
+            }
+        }
+
+        // Double-check: the number of edges should be n*(n-1)/2
+        let expected_edge_count = self.n_vertices * (self.n_vertices - 1) / 2;
+        if self.n_edges != expected_edge_count {
+            return false;
+        }
+
+        true
+    }
+
+    /// Check if the graph is a cycle graph (connected, and each vertex has exactly 2 neighbors)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zagreb_lib::Graph;
+    ///
+    /// assert!(Graph::cycle(5).is_cycle());
+    /// ```
+    pub fn is_cycle(&self) -> bool {
+        // For a cycle, every vertex has degree 2; disjoint unions of cycles are not connected
+        self.min_degree() == 2
+            && self.max_degree() == 2
+            && self.n_edges == self.n_vertices
+            && self.is_connected()
+    }
+
+    /// Check if the graph is a star graph (one central vertex connected to all others)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zagreb_lib::Graph;
+    ///
+    /// assert!(Graph::star(5).is_star());
+    /// ```
+    pub fn is_star(&self) -> bool {
+        if self.n_vertices <= 1 {
+            return false;
+        }
+
+        // Count vertices of degree 1
+        let degree_one_count = (0..self.n_vertices)
+            .filter(|&v| self.edges[v].len() == 1)
+            .count();
+
+        // Count vertices of degree n-1
+        let degree_n_minus_1_count = (0..self.n_vertices)
+            .filter(|&v| self.edges[v].len() == self.n_vertices - 1)
+            .count();
+
+        // A star has exactly one vertex with degree n-1 and n-1 vertices with degree 1
+        degree_one_count == self.n_vertices - 1 && degree_n_minus_1_count == 1
+    }
+
+    /// Check if the graph is a path graph (a tree with exactly 2 leaves)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zagreb_lib::Graph;
+    ///
+    /// assert!(Graph::path(4).is_path());
+    /// ```
+    pub fn is_path(&self) -> bool {
+        // For a path, we have exactly n-1 edges
+        if self.n_edges + 1 != self.n_vertices {
+            return false;
+        }
+
+        // A path has exactly 2 vertices with degree 1, and the rest have degree 2
+        let degree_one_count = (0..self.n_vertices)
+            .filter(|&v| self.edges[v].len() == 1)
+            .count();
+
+        let degree_two_count = (0..self.n_vertices)
+            .filter(|&v| self.edges[v].len() == 2)
+            .count();
+
+        // Connectivity rules out a shorter path alongside disjoint cycles
+        degree_one_count == 2 && degree_two_count == self.n_vertices - 2 && self.is_connected()
+    }
+
+    /// Check if the graph is a tree (connected with exactly n-1 edges)
+    /// The graph with no vertices is not a tree
+    pub fn is_tree(&self) -> bool {
+        self.n_vertices > 0 && self.n_edges + 1 == self.n_vertices && self.is_connected()
+    }
+
+    /// Check if the graph is a forest (has no cycles)
+    /// A graph is acyclic iff every component is a tree, i.e. it has exactly n - m components
+    pub fn is_forest(&self) -> bool {
+        self.connected_components().len() + self.n_edges == self.n_vertices
+    }
+
+    /// Calculate upper bound on Zagreb index using Theorem 3 from the paper
+    pub fn zagreb_upper_bound(&self) -> f64 {
+        // The bound divides by the independence number, which is 0 only for the empty graph
+        if self.n_vertices == 0 {
+            return 0.0;
+        }
+
+        let beta = self.independence_number_approx();
+        let delta = self.min_degree();
+        let n = self.n_vertices;
+        let e = self.n_edges;
+        let delta_max = self.max_degree();
+
+        // Apply Theorem 3 from the paper
+        let part1 = (n - beta) * delta_max * delta_max;
+        let part2 = (e * e) as f64 / beta as f64;
+        let part3 = ((n - beta) as f64).sqrt() - (delta as f64).sqrt();
+        let part3_squared = part3 * part3;
+
+        part1 as f64 + part2 + part3_squared * e as f64
+    }
+
+    /// Get every edge once as a `(u, v)` pair with `u < v`, sorted lexicographically
+    pub fn edge_list(&self) -> Vec<(usize, usize)> {
+        let mut edges: Vec<(usize, usize)> = (0..self.n_vertices)
+            .flat_map(|u| {
+                self.edges[u]
+                    .iter()
+                    .filter(move |&&v| u < v)
+                    .map(move |&v| (u, v))
+            })
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    /// Write the edges as CSV with a `source,target` header and one `u,v` row per edge
+    /// Rows follow the order of `edge_list`; isolated vertices do not appear
+    pub fn to_edge_csv(&self) -> String {
+        let mut csv = String::from("source,target\n");
+        for (u, v) in self.edge_list() {
+            csv.push_str(&format!("{},{}\n", u, v));
+        }
+        csv
+    }
+
+    /// Convert to a petgraph `UnGraph`, where vertex v becomes the node with index v
+    /// Edge weights are dropped
+    #[cfg(feature = "petgraph")]
+    pub fn to_petgraph(&self) -> petgraph::graph::UnGraph<(), ()> {
+        use petgraph::graph::{NodeIndex, UnGraph};
+
+        let mut graph = UnGraph::with_capacity(self.n_vertices, self.n_edges);
+        for _ in 0..self.n_vertices {
+            graph.add_node(());
+        }
+        for (u, v) in self.edge_list() {
+            graph.add_edge(NodeIndex::new(u), NodeIndex::new(v), ());
+        }
+        graph
+    }
+
+    /// Build a graph from a petgraph `UnGraph`, where the node with index v becomes vertex v
+    /// Node and edge weights are ignored and parallel edges collapse into one. Self-loops are
+    /// rejected, since `Graph` is simple.
+    #[cfg(feature = "petgraph")]
+    pub fn from_petgraph<N, E>(graph: &petgraph::graph::UnGraph<N, E>) -> Result<Graph, GraphError> {
+        Graph::from_edges(
+            graph.node_count(),
+            graph
+                .raw_edges()
+                .iter()
+                .map(|edge| (edge.source().index(), edge.target().index())),
+        )
+    }
+
+    /// Export the graph as a symmetric 0/1 adjacency matrix
+    pub fn to_adjacency_matrix(&self) -> Vec<Vec<u8>> {
+        let mut matrix = vec![vec![0; self.n_vertices]; self.n_vertices];
+
+        for (u, row) in matrix.iter_mut().enumerate() {
+            for &v in &self.edges[u] {
+                row[v] = 1;
+            }
+        }
+
+        matrix
+    }
+
+    /// Build the Seidel adjacency matrix `J - I - 2A`
+    /// Entries are 0 on the diagonal, -1 for adjacent pairs and 1 for non-adjacent pairs
+    pub fn seidel_matrix(&self) -> Vec<Vec<i64>> {
+        let mut matrix = vec![vec![1; self.n_vertices]; self.n_vertices];
+
+        for (u, row) in matrix.iter_mut().enumerate() {
+            row[u] = 0;
+            for &v in &self.edges[u] {
+                row[v] = -1;
+            }
+        }
+
+        matrix
+    }
+
+    /// Compute the eigenvalues of the Seidel matrix in ascending order
+    /// The Seidel spectrum is invariant under Seidel switching, so graphs in the same switching
+    /// class share it
+    pub fn seidel_spectrum(&self) -> Vec<f64> {
+        let matrix = self
+            .seidel_matrix()
+            .into_iter()
+            .map(|row| row.into_iter().map(|x| x as f64).collect())
+            .collect();
+
+        symmetric_eigenvalues(matrix)
+    }
+
+    /// Estimate the spectral radius, the largest eigenvalue of the adjacency matrix
+    ///
+    /// Runs power iteration on the implicit adjacency lists, so each step is O(n + m) and no
+    /// dense matrix is built. The iteration uses A + I, which has the same eigenvectors but keeps
+    /// bipartite graphs (whose spectrum is symmetric about 0) from oscillating. For a k-regular
+    /// graph this is k, and in general it lies between the average and the maximum degree.
+    pub fn spectral_radius(&self) -> f64 {
+        if self.n_edges == 0 {
+            return 0.0;
+        }
+
+        let n = self.n_vertices;
+        // The all-ones start vector is never orthogonal to the non-negative Perron vector
+        let mut x = vec![1.0 / (n as f64).sqrt(); n];
+        let mut radius = 0.0;
+
+        for _ in 0..10_000 {
+            let y: Vec<f64> = (0..n)
+                .map(|u| x[u] + self.edges[u].iter().map(|&v| x[v]).sum::<f64>())
+                .collect();
+
+            // Rayleigh quotient of the unit vector x, minus the shift
+            let estimate = x.iter().zip(&y).map(|(a, b)| a * b).sum::<f64>() - 1.0;
+            let norm = y.iter().map(|v| v * v).sum::<f64>().sqrt();
+            x = y.into_iter().map(|v| v / norm).collect();
+
+            if (estimate - radius).abs() <= 1e-12 * estimate.max(1.0) {
+                return estimate;
+            }
+            radius = estimate;
+        }
+
+        radius
+    }
+
+    /// Estimate the algebraic connectivity (Fiedler value), the second-smallest Laplacian eigenvalue
+    ///
+    /// Returns 0.0 for disconnected graphs and graphs with fewer than 2 vertices. Otherwise runs
+    /// power iteration on the shifted matrix 2Δ·I - L, projecting out the all-ones kernel of L at
+    /// every step, so the dominant remaining eigenvalue is 2Δ - λ₂. Each step is O(n + m), but
+    /// convergence slows as the gap between λ₂ and λ₃ shrinks, e.g. on long paths.
+    pub fn algebraic_connectivity(&self) -> f64 {
+        let n = self.n_vertices;
+        if n < 2 || !self.is_connected() {
+            return 0.0;
+        }
+
+        // Every Laplacian eigenvalue is at most 2Δ, so the shifted matrix is positive semidefinite
+        let shift = 2.0 * self.max_degree() as f64;
+        let project = |x: &mut Vec<f64>| {
+            let mean = x.iter().sum::<f64>() / n as f64;
+            x.iter_mut().for_each(|v| *v -= mean);
+            let norm = x.iter().map(|v| v * v).sum::<f64>().sqrt();
+            x.iter_mut().for_each(|v| *v /= norm);
+        };
+
+        // An irregular start vector avoids being orthogonal to the Fiedler vector by symmetry
+        let mut x: Vec<f64> = (0..n).map(|v| (v as f64 * 0.618_033_988_749_895).fract()).collect();
+        project(&mut x);
+        let mut fiedler = f64::MAX;
+
+        for _ in 0..100_000 {
+            // y = (shift·I - L) x, where (L x)_u = d(u)·x_u - sum of x over the neighbors of u
+            let mut y: Vec<f64> = (0..n)
+                .map(|u| {
+                    let neighbor_sum = self.edges[u].iter().map(|&v| x[v]).sum::<f64>();
+                    (shift - self.edges[u].len() as f64) * x[u] + neighbor_sum
+                })
+                .collect();
+
+            let estimate = shift - x.iter().zip(&y).map(|(a, b)| a * b).sum::<f64>();
+            project(&mut y);
+            x = y;
+
+            if (estimate - fiedler).abs() <= 1e-12 * shift {
+                return estimate;
+            }
+            fiedler = estimate;
+        }
+
+        fiedler
+    }
+
+    /// Compute the main metrics of the graph in one call
+    pub fn report(&self) -> GraphReport {
+        GraphReport {
+            vertex_count: self.vertex_count(),
+            edge_count: self.edge_count(),
+            min_degree: self.min_degree(),
+            max_degree: self.max_degree(),
+            first_zagreb_index: self.first_zagreb_index(),
+            wiener_index: self.wiener_index(),
+            edge_connectivity: self.edge_connectivity(),
+            independence_number: self.independence_number_approx(),
+            is_likely_hamiltonian: self.is_likely_hamiltonian_with(false),
+            is_likely_traceable: self.is_likely_traceable_with(false),
+            zagreb_upper_bound: self.zagreb_upper_bound(),
+        }
+    }
+
+    /// Export the graph structure and its `GraphReport` as one JSON document
+    /// The document carries a `schema_version` field (see `ANALYSIS_SCHEMA_VERSION`)
+    #[cfg(feature = "serde")]
+    pub fn to_analysis_json(&self) -> String {
+        let document = AnalysisDocument {
+            schema_version: ANALYSIS_SCHEMA_VERSION,
+            graph: self,
+            report: self.report(),
+        };
+
+        serde_json::to_string_pretty(&document).expect("analysis document is always serializable")
+    }
+
+    /// Export the graph in Graphviz DOT format
+    /// Each undirected edge is written once, and isolated vertices are declared so they still render
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("graph G {\n");
+
+        for u in 0..self.n_vertices {
+            let mut neighbors: Vec<usize> = self.edges[u].iter().cloned().collect();
+            if neighbors.is_empty() {
+                dot.push_str(&format!("  {};\n", u));
+                continue;
+            }
+
+            neighbors.sort_unstable();
+            for v in neighbors.into_iter().filter(|&v| v > u) {
+                dot.push_str(&format!("  {} -- {};\n", u, v));
+            }
+        }
+
+        dot.push('}');
+        dot
+    }
+
+    /// Get the number of vertices
+    pub fn vertex_count(&self) -> usize {
+        self.n_vertices
+    }
+
+    /// Get the number of edges
+    pub fn edge_count(&self) -> usize {
+        self.n_edges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::thread_rng;
+    use super::*;
+
+    #[test]
+    fn test_k_connectivity_exact_vs_approx() {
+        // Test on various graph types
+
+        // 1. Complete graph (should be (n-1)-connected)
+        let complete = Graph::complete(6);
+
+        // Verify that is_complete works correctly
+        assert!(
+            complete.is_complete(),
+            "Complete graph detection should work"
+        );
+
+        for k in 1..=5 {
+            assert_eq!(
+                complete.is_k_connected_exact(k),
+                true,
+                "Complete graph (n=6) should be {}-connected with exact algorithm",
+                k
+            );
+
+            assert_eq!(
+                complete.is_k_connected_approx(k),
+                true,
+                "Complete graph (n=6) should be {}-connected with approximate algorithm",
+                k
+            );
+
+            // Also test the wrapper function
+            assert_eq!(
+                complete.is_k_connected_with(k, true),
+                true,
+                "Complete graph (n=6) should be {}-connected with wrapper (exact)",
+                k
+            );
+
+            assert_eq!(
+                complete.is_k_connected(k),
+                true,
+                "Complete graph (n=6) should be {}-connected with wrapper (approx)",
+                k
+            );
+        }
+
+        // A complete graph with n vertices is (n-1)-connected but not n-connected
+        // Test the wrapper function first (most important to users)
+        assert_eq!(
+            complete.is_k_connected(6),
+            false,
+            "Complete graph (n=6) should not be 6-connected with wrapper (approx)"
+        );
+
+        // Then test both individual functions
+        assert_eq!(
+            complete.is_k_connected_approx(6),
+            false,
+            "Complete graph (n=6) should not be 6-connected with approximate algorithm"
+        );
+
+        assert_eq!(
+            complete.is_k_connected_exact(6),
+            false,
+            "Complete graph (n=6) should not be 6-connected with exact algorithm"
+        );
+
+        // 2. Cycle graph (should be 2-connected but not 3-connected)
+        let cycle = Graph::cycle(5);
+
+        assert_eq!(
+            cycle.is_k_connected_exact(1),
+            true,
+            "Cycle graph should be 1-connected with exact algorithm"
+        );
+
+        assert_eq!(
+            cycle.is_k_connected_exact(2),
+            true,
+            "Cycle graph should be 2-connected with exact algorithm"
+        );
+
+        assert_eq!(
+            cycle.is_k_connected_exact(3),
+            false,
+            "Cycle graph should not be 3-connected with exact algorithm"
+        );
+
+        // Both algorithms should agree on these simple cases
+        assert_eq!(
+            cycle.is_k_connected_approx(1),
+            cycle.is_k_connected_exact(1),
+            "Approximation and exact algorithms should agree for cycle graph with k=1"
+        );
+
+        assert_eq!(
+            cycle.is_k_connected_approx(2),
+            cycle.is_k_connected_exact(2),
+            "Approximation and exact algorithms should agree for cycle graph with k=2"
+        );
+
+        assert_eq!(
+            cycle.is_k_connected_approx(3),
+            cycle.is_k_connected_exact(3),
+            "Approximation and exact algorithms should agree for cycle graph with k=3"
+        );
+
+        // 3. Path graph (should be 1-connected but not 2-connected)
+        let path = Graph::path(5);
+
+        assert_eq!(
+            path.is_k_connected_exact(1),
+            true,
+            "Path graph should be 1-connected with exact algorithm"
+        );
+
+        assert_eq!(
+            path.is_k_connected_exact(2),
+            false,
+            "Path graph should not be 2-connected with exact algorithm"
+        );
+
+        // Both algorithms should agree on these simple cases
+        assert_eq!(
+            path.is_k_connected_approx(1),
+            path.is_k_connected_exact(1),
+            "Approximation and exact algorithms should agree for path graph with k=1"
+        );
+
+        assert_eq!(
+            path.is_k_connected_approx(2),
+            path.is_k_connected_exact(2),
+            "Approximation and exact algorithms should agree for path graph with k=2"
+        );
+
+        // 4. Test on a small Petersen-like graph (should be 3-connected but not 4-connected)
+        // Using a smaller test graph to avoid long test times
+        let test_graph = Graph::from_edges(
+            6,
+            [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (0, 3), (1, 4), (2, 5)],
+        )
+        .unwrap();
+
+        assert_eq!(
+            test_graph.is_k_connected_exact(3),
+            true,
+            "Test graph should be 3-connected with exact algorithm"
+        );
+
+        assert_eq!(
+            test_graph.is_k_connected_exact(4),
+            false,
+            "Test graph should not be 4-connected with exact algorithm"
+        );
+    }
+
+    #[test]
+    fn test_find_path() {
+        // Simple path test on a line graph
+        let path_graph = Graph::path(5);
+
+        // There should be a path from 0 to 4
+        let path = path_graph.find_path(0, 4);
+        assert!(path.is_some(), "Should find a path from 0 to 4");
+
+        let path_vertices = path.unwrap();
+        assert_eq!(path_vertices.len(), 5, "Path should visit 5 vertices");
+        assert_eq!(path_vertices[0], 0, "Path should start at vertex 0");
+        assert_eq!(path_vertices[4], 4, "Path should end at vertex 4");
+
+        // Test on a disconnected graph
+        let disconnected = Graph::from_edges(5, [(0, 1), (1, 2)]).unwrap();
+        // No connection to vertices 3 and 4
+
+        let path = disconnected.find_path(0, 4);
+        assert!(
+            path.is_none(),
+            "Should not find a path in disconnected graph"
+        );
+
+        // Test find_path_in_subgraph with custom edges
+        let mut custom_edges = vec![HashSet::new(); 5];
+
+        // Create a different path: 0-2-4
+        custom_edges[0].insert(2);
+        custom_edges[2].insert(0);
+        custom_edges[2].insert(4);
+        custom_edges[4].insert(2);
+
+        let custom_path = path_graph.find_path_in_subgraph(&custom_edges, 0, 4);
+        assert!(custom_path.is_some(), "Should find a custom path");
+
+        let custom_path_vertices = custom_path.unwrap();
+        assert_eq!(
+            custom_path_vertices.len(),
+            3,
+            "Custom path should visit 3 vertices"
+        );
+        assert_eq!(
+            custom_path_vertices[0], 0,
+            "Custom path should start at vertex 0"
+        );
+        assert_eq!(
+            custom_path_vertices[1], 2,
+            "Custom path should go through vertex 2"
+        );
+        assert_eq!(
+            custom_path_vertices[2], 4,
+            "Custom path should end at vertex 4"
+        );
+    }
+
+    #[test]
+    fn test_find_vertex_disjoint_paths() {
+        // Complete graph with 5 vertices
+        let complete = Graph::complete(5);
+
+        // In a complete graph K5, there are 4 vertex-disjoint paths between any two vertices
+        // (1 direct edge + 3 paths through other vertices)
+        let disjoint_paths = complete.find_vertex_disjoint_paths(0, 1);
+        assert_eq!(
+            disjoint_paths, 4,
+            "Complete graph K5 should have 4 vertex-disjoint paths between any two vertices"
+        );
+
+        // Cycle graph
+        let cycle = Graph::cycle(5);
+
+        // Should have 2 vertex-disjoint paths between any two non-adjacent vertices
+        let disjoint_paths = cycle.find_vertex_disjoint_paths(0, 2);
+        assert_eq!(
+            disjoint_paths, 2,
+            "Cycle graph should have 2 vertex-disjoint paths between any two non-adjacent vertices"
+        );
+
+        // Check adjacent vertices in cycle
+        let disjoint_paths_adj = cycle.find_vertex_disjoint_paths(0, 1);
+        assert_eq!(
+            disjoint_paths_adj, 2,
+            "Cycle graph should handle adjacent vertices correctly"
+        );
+
+        // Path graph
+        let path = Graph::path(5);
+
+        // Should have 1 vertex-disjoint path between end vertices
+        let disjoint_paths = path.find_vertex_disjoint_paths(0, 4);
+        assert_eq!(
+            disjoint_paths, 1,
+            "Path graph should have 1 vertex-disjoint path between end vertices"
+        );
+
+        // Test on a small graph with 6 vertices
+        let test_graph = Graph::from_edges(
+            6,
+            [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (0, 3), (1, 4), (2, 5)],
+        )
+        .unwrap();
+
+        // Test graph should have 3 vertex-disjoint paths between vertices 0 and 5
+        let disjoint_paths = test_graph.find_vertex_disjoint_paths(0, 5);
+        assert_eq!(
+            disjoint_paths, 3,
+            "Test graph should have 3 vertex-disjoint paths between vertices 0 and 5"
+        );
+    }
+
+    #[test]
+    fn test_cycle_graph() {
+        // Create a cycle graph with 5 vertices (should be Hamiltonian)
+        let graph = Graph::cycle(5);
+
+        assert_eq!(graph.first_zagreb_index(), 20); // Each vertex has degree 2, so 5 * 2^2 = 20
+        assert_eq!(graph.min_degree(), 2);
+        assert_eq!(graph.max_degree(), 2);
+        assert_eq!(graph.edge_count(), 5);
+
+        // A cycle is its own Hamiltonian cycle
+        assert!(graph.is_likely_hamiltonian_with(false));
+        assert!(graph.is_likely_traceable_with(false));
+    }
+
+    #[test]
+    fn test_complete_graph() {
+        // Create a complete graph with 6 vertices (should be Hamiltonian)
+        let graph = Graph::complete(6);
+
+        // Each vertex has degree 5, so 6 * 5^2 = 150
+        assert_eq!(graph.first_zagreb_index(), 150);
+        assert_eq!(graph.min_degree(), 5);
+        assert_eq!(graph.max_degree(), 5);
+        assert_eq!(graph.edge_count(), 15);
+
+        // Complete graphs with n > 2 are always Hamiltonian
+        assert!(graph.is_likely_hamiltonian_with(false));
+        assert!(graph.is_likely_traceable_with(false));
+    }
+
+    #[test]
+    fn test_star_graph() {
+        // Create a star graph with 5 vertices (center and 4 leaves)
+        // Star graphs are not Hamiltonian for n > 3
+        let graph = Graph::star(5);
+
+        // Center has degree 4, leaves have degree 1, so 4^2 + 4*1^2 = 20
+        assert_eq!(graph.first_zagreb_index(), 20);
+        assert_eq!(graph.min_degree(), 1);
+        assert_eq!(graph.max_degree(), 4);
+        assert_eq!(graph.edge_count(), 4);
+
+        // Star graphs with 5 vertices are not Hamiltonian
+        assert!(!graph.is_likely_hamiltonian_with(false));
+        // But they are traceable
+        assert!(graph.is_likely_traceable_with(false));
+    }
+
+    #[test]
+    fn test_petersen_graph() {
+        // Create the Petersen graph (10 vertices, 3-regular, non-Hamiltonian)
+        let graph = Graph::petersen();
+
+        // Verify basic properties
+        assert_eq!(graph.vertex_count(), 10);
+        assert_eq!(graph.edge_count(), 15);
+        assert_eq!(graph.min_degree(), 3); // 3-regular graph
+        assert_eq!(graph.max_degree(), 3); // 3-regular graph
+
+        // Calculate Zagreb index: 10 vertices with degree 3, so 10 * 3^2 = 90
+        assert_eq!(graph.first_zagreb_index(), 90);
+
+        // Petersen graph is 3-connected
+        assert!(graph.is_k_connected(3));
+
+        // Petersen graph is NOT Hamiltonian (famous result in graph theory)
+        assert!(!graph.is_likely_hamiltonian_with(false));
+
+        // Petersen graph IS traceable (it has a Hamiltonian path)
+        assert!(graph.is_likely_traceable_with(false));
+
+        // Test independent set properties
+        // Petersen graph's independence number is 4
+        let independence_num = graph.independence_number_approx();
+        assert!(
+            independence_num >= 4,
+            "Expected independence number >= 4, got {}",
+            independence_num
+        );
+    }
+
+    #[test]
+    fn test_zagreb_index_calculation() {
+        // Complete graph K5 - each vertex has degree 4, so sum of squares is 5 * 4^2 = 80
+        let complete5 = Graph::complete(5);
+        assert_eq!(complete5.first_zagreb_index(), 80);
+
+        // Path graph P5 - two vertices of degree 1, three vertices of degree 2, so 2*1^2 + 3*2^2 = 14
+        let path5 = Graph::path(5);
+        assert_eq!(path5.first_zagreb_index(), 14);
+
+        // Empty graph
+        let empty = Graph::new(5);
+        assert_eq!(empty.first_zagreb_index(), 0);
+
+        // Single vertex graph
+        let single = Graph::new(1);
+        assert_eq!(single.first_zagreb_index(), 0);
+    }
+
+    #[test]
+    fn test_hamiltonian_detection() {
+        // Known Hamiltonian graphs
+        let complete5 = Graph::complete(5);
+        assert!(complete5.is_likely_hamiltonian_with(true));
+
+        let cycle5 = Graph::cycle(5);
+        assert!(cycle5.is_likely_hamiltonian_with(true));
+
+        // Known non-Hamiltonian graphs
+        let star5 = Graph::star(5);
+        assert!(!star5.is_likely_hamiltonian_with(true));
+
+        // Create Petersen graph (known to be non-Hamiltonian)
+        let petersen = Graph::petersen();
+        assert!(!petersen.is_likely_hamiltonian_with(true));
+    }
+
+    #[test]
+    fn test_traceable_detection() {
+        // Test path graph (traceable by definition)
+        let path = Graph::path(5);
+        assert!(path.is_likely_traceable_with(true));
+
+        // Test star graph (traceable)
+        let star = Graph::star(5);
+        assert!(star.is_likely_traceable_with(true));
+
+        // Test Petersen graph (known to be traceable)
+        let petersen = Graph::petersen();
+        assert!(petersen.is_likely_traceable_with(true));
+    }
+
+    #[test]
+    fn test_zagreb_upper_bound() {
+        // Create various graph types
+        let cycle = Graph::cycle(5);
+
+        let complete = Graph::complete(5);
+
+        let star = Graph::star(5);
+
+        // Verify the Zagreb index is always less than or equal to the upper bound
+        assert!(cycle.first_zagreb_index() as f64 <= cycle.zagreb_upper_bound());
+        assert!(complete.first_zagreb_index() as f64 <= complete.zagreb_upper_bound());
+        assert!(star.first_zagreb_index() as f64 <= star.zagreb_upper_bound());
+    }
+
+    #[test]
+    fn test_graph_type_detection() {
+        // Test complete graph detection
+        let complete = Graph::complete(5);
+        assert!(complete.is_complete());
+
+        // Test cycle graph detection
+        let cycle = Graph::cycle(5);
+        assert!(cycle.is_cycle());
+
+        // Test star graph detection
+        let star = Graph::star(5);
+        assert!(star.is_star());
+
+        // Test path graph detection
+        let path = Graph::path(5);
+        assert!(path.is_path());
+
+        // Test non-matches
+        assert!(!cycle.is_complete());
+        assert!(!star.is_cycle());
+        assert!(!path.is_star());
+        assert!(!complete.is_path());
+    }
+
+    #[test]
+    fn test_theorem_implementations() {
+        // Test Theorem 1 with k=2
+        let mut graph = Graph::new(10);
+        // Create a k-connected graph (k=2) that meets the Zagreb index criteria
+        // and verify it's correctly identified as Hamiltonian
+        // This would need to be constructed based on the theorem's specifics
+
+        // Test Theorem 2 with k=1
+        // Similarly construct and test
+
+        // Test Theorem 3 upper bounds
+        // Create a graph and verify the bounds match expected values
+    }
+
+    #[test]
+    fn test_independence_number() {
+        // Test on a path graph P5 (should be 3)
+        let path = Graph::path(5);
+        assert_eq!(path.independence_number_approx(), 3);
+
+        // Test on a cycle graph C5 (should be 2)
+        let cycle = Graph::cycle(5);
+        assert_eq!(cycle.independence_number_approx(), 2);
+
+        // Test on a complete graph K5 (should be 1)
+        let complete = Graph::complete(5);
+        assert_eq!(complete.independence_number_approx(), 1);
+    }
+
+    #[test]
+    fn test_theorem_1_implementation() {
+        // Theorem 1 deals with Hamiltonian properties for k-connected graphs (k ≥ 2)
+
+        // First, check if the implementation correctly identifies known Hamiltonian graphs
+        let complete5 = Graph::complete(5);
+        assert!(complete5.is_likely_hamiltonian_with(false),
+                "Complete graph K5 should be identified as Hamiltonian");
+
+        let cycle6 = Graph::cycle(6);
+        assert!(cycle6.is_likely_hamiltonian_with(false),
+                "Cycle graph C6 should be identified as Hamiltonian");
+
+        // Now create a graph that satisfies the conditions from the paper
+        // We'll create a k-connected graph for k=2
+        // Create a cycle as base structure (ensures 2-connectivity)
+        let mut graph1 = Graph::cycle(8);
+        // Add diagonals to increase Zagreb index
+        graph1.add_edge(0, 2).unwrap();
+        graph1.add_edge(0, 3).unwrap();
+        graph1.add_edge(0, 4).unwrap();
+        graph1.add_edge(1, 3).unwrap();
+        graph1.add_edge(1, 4).unwrap();
+        graph1.add_edge(1, 5).unwrap();
+        graph1.add_edge(2, 4).unwrap();
+        graph1.add_edge(2, 5).unwrap();
+        graph1.add_edge(2, 6).unwrap();
+        graph1.add_edge(3, 5).unwrap();
+        graph1.add_edge(3, 6).unwrap();
+        graph1.add_edge(3, 7).unwrap();
+        graph1.add_edge(4, 6).unwrap();
+        graph1.add_edge(4, 7).unwrap();
+        graph1.add_edge(5, 7).unwrap();
+
+        let k = 2;
+        let n = graph1.vertex_count();
+        let e = graph1.edge_count();
+        let delta = graph1.min_degree();
+        let delta_max = graph1.max_degree();
+        let z1 = graph1.first_zagreb_index();
+
+        // Calculate Theorem 1 threshold
+        let part1 = (n - k - 1) * delta_max * delta_max;
+        let part2 = (e * e) / (k + 1);
+        let part3 = ((n - k - 1) as f64).sqrt() - (delta as f64).sqrt();
+        let part3_squared = part3 * part3;
+        let threshold = part1 + part2 + (part3_squared * e as f64) as usize;
+
+        println!("Theorem 1 test: n={}, k={}, e={}, delta={}, delta_max={}",
+                 n, k, e, delta, delta_max);
+        println!("Theorem 1 test: Zagreb index = {}, threshold = {}", z1, threshold);
+
+        // It's okay if the graph doesn't meet the threshold as long as it's Hamiltonian
+        // The paper provides a sufficient (but not necessary) condition
+        let hamiltonian_by_property = graph1.is_likely_hamiltonian_with(false);
+        println!("Is Hamiltonian according to implementation: {}", hamiltonian_by_property);
+
+        // For this test, we'll check if the implementation agrees with known Hamiltonian properties
+        assert!(hamiltonian_by_property,
+                "The graph should be identified as Hamiltonian");
+
+        // Test the special case mentioned in the paper: K_{k,k+1}
+        // For k=2, we shouldn't hard-code whether it's Hamiltonian or not,
+        // because the implementation might handle this case specially
+        // Instead, let's just print whether the implementation thinks it's Hamiltonian
+        // Connect vertices 0,1 to vertices 2,3,4
+        let bipartite =
+            Graph::from_edges(5, [(0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4)]).unwrap();
+
+        let bipartite_hamiltonian = bipartite.is_likely_hamiltonian_with(false);
+        println!("K_{{2,3}} bipartite graph is Hamiltonian according to implementation: {}",
+                 bipartite_hamiltonian);
+
+        // Based on the paper, K_{k,k+1} is NOT Hamiltonian for k≥2
+        // However, we'll check if the implementation is consistent with itself
+
+        // Check if the implementation handles K_{k,k+1} as a special case
+        let special_case_handled = bipartite.is_k_connected(k) &&
+            !bipartite_hamiltonian;
+
+        println!("K_{{2,3}} is k-connected: {}", bipartite.is_k_connected(k));
+        println!("Special case K_{{k,k+1}} handled: {}", special_case_handled);
+
+        // If the implementation doesn't specially handle K_{k,k+1}, then we don't enforce that it's non-Hamiltonian
+        // Otherwise, we'll check that it correctly identifies it as non-Hamiltonian
+        if special_case_handled {
+            assert!(!bipartite_hamiltonian,
+                    "K_{{2,3}} bipartite graph should be identified as non-Hamiltonian if special cases are handled");
+        }
+    }
+
+    #[test]
+    fn test_theorem_2_implementation() {
+        // Theorem 2 deals with traceable properties for k-connected graphs (k ≥ 1)
+
+        // First, check if the implementation correctly identifies known traceable graphs
+        let path5 = Graph::path(5);
+        assert!(path5.is_likely_traceable_with(false),
+                "Path graph P5 should be identified as traceable");
+
+        let star5 = Graph::star(5);
+        assert!(star5.is_likely_traceable_with(false),
+                "Star graph K_{{1,4}} should be identified as traceable");
+
+        // The simplest traceable graph is a path
+        // Let's create a path and verify the implementation identifies it correctly
+        let simple_path = Graph::path(10);
+
+        let simple_path_traceable = simple_path.is_likely_traceable_with(false);
+        println!("Simple path P10 is traceable according to implementation: {}",
+                 simple_path_traceable);
+
+        assert!(simple_path_traceable,
+                "A simple path graph P10 should be identified as traceable");
+
+        // Now let's test a more complex graph where we add edges to the path
+        // but make sure it remains traceable
+        // Base path to ensure traceability
+        let mut complex_path = Graph::path(10);
+
+        // Add a few strategically placed edges that don't affect traceability
+        complex_path.add_edge(0, 2).unwrap();
+        complex_path.add_edge(2, 4).unwrap();
+        complex_path.add_edge(4, 6).unwrap();
+        complex_path.add_edge(6, 8).unwrap();
+
+        let k = 1;
+        let n = complex_path.vertex_count();
+        let e = complex_path.edge_count();
+        let delta = complex_path.min_degree();
+        let delta_max = complex_path.max_degree();
+        let z1 = complex_path.first_zagreb_index();
+
+        // Calculate Theorem 2 threshold
+        let part1 = (n - k - 2) * delta_max * delta_max;
+        let part2 = (e * e) / (k + 2);
+        let part3 = ((n - k - 2) as f64).sqrt() - (delta as f64).sqrt();
+        let part3_squared = part3 * part3;
+        let threshold = part1 + part2 + (part3_squared * e as f64) as usize;
+
+        println!("Theorem 2 test with complex path: n={}, k={}, e={}, delta={}, delta_max={}",
+                 n, k, e, delta, delta_max);
+        println!("Theorem 2 test: Zagreb index = {}, threshold = {}", z1, threshold);
+
+        let complex_path_traceable = complex_path.is_likely_traceable_with(false);
+        println!("Complex path is traceable according to implementation: {}",
+                 complex_path_traceable);
+
+        // Check with exact connectivity calculation as well
+        let complex_path_traceable_exact = complex_path.is_likely_traceable_with(true);
+        println!("Complex path is traceable with exact connectivity check: {}",
+                 complex_path_traceable_exact);
+
+        // Print other relevant information
+        println!("Complex path is 1-connected: {}", complex_path.is_k_connected(1));
+        println!("Complex path is identified as a path: {}", complex_path.is_path());
+
+        // Instead of strict assertion, print diagnostic information if the implementation
+        // doesn't behave as expected
+        if !complex_path_traceable {
+            println!("WARNING: The implementation doesn't identify a complex path as traceable");
+            println!("This may indicate an issue with the traceable detection algorithm");
+        }
+
+        // Test special case: K_{k,k+2}
+        // For k=1, K_{1,3} is actually traceable even though it's the form K_{k,k+2}
+        let small_bipartite = Graph::star(4);
+
+        let small_bipartite_traceable = small_bipartite.is_likely_traceable_with(false);
+        println!("K_{{1,3}} bipartite graph is traceable according to implementation: {}",
+                 small_bipartite_traceable);
+
+        assert!(small_bipartite_traceable,
+                "K_{{1,3}} bipartite graph should be identified as traceable");
+
+        // For a better test, use k=2 where K_{2,4} is mentioned in the paper
+        let mut bipartite = Graph::new(6);
+        // Connect vertices 0,1 to vertices 2,3,4,5
+        for i in 0..2 {
+            for j in 2..6 {
+                bipartite.add_edge(i, j).unwrap();
+            }
+        }
+
+        let bipartite_traceable = bipartite.is_likely_traceable_with(false);
+        println!("K_{{2,4}} bipartite graph is traceable according to implementation: {}",
+                 bipartite_traceable);
+
+        // No hard assertion here, just documenting whether the implementation handles the special case
+        println!("K_{{2,4}} is 2-connected: {}", bipartite.is_k_connected(2));
+
+        // Create and test a cycle graph which is both Hamiltonian and traceable
+        let cycle = Graph::cycle(10);
+
+        let cycle_traceable = cycle.is_likely_traceable_with(false);
+        println!("Cycle C10 is traceable according to implementation: {}", cycle_traceable);
+
+        assert!(cycle_traceable, "Cycle graph C10 should be identified as traceable");
+    }
+
+    #[test]
+    fn test_theorem_3_upper_bound() {
+        // Theorem 3 deals with upper bounds for the Zagreb index
+
+        // Test on various graph types to verify the upper bound holds
+
+        // Test on a complete graph K_5
+        let complete = Graph::complete(5);
+
+        // Calculate actual Zagreb index
+        let z1_complete = complete.first_zagreb_index();
+
+        // Calculate upper bound using Theorem 3
+        let upper_bound_complete = complete.zagreb_upper_bound();
+
+        // The Zagreb index should not exceed the upper bound
+        assert!(z1_complete as f64 <= upper_bound_complete,
+                "Zagreb index {} should not exceed upper bound {} for complete graph",
+                z1_complete, upper_bound_complete);
+
+        println!("K_5: Zagreb index = {}, upper bound = {}",
+                 z1_complete, upper_bound_complete);
+
+        // Test on a cycle graph C_6
+        let cycle = Graph::cycle(6);
+
+        let z1_cycle = cycle.first_zagreb_index();
+        let upper_bound_cycle = cycle.zagreb_upper_bound();
+
+        // The Zagreb index should not exceed the upper bound
+        assert!(z1_cycle as f64 <= upper_bound_cycle,
+                "Zagreb index {} should not exceed upper bound {} for cycle graph",
+                z1_cycle, upper_bound_cycle);
+
+        println!("C_6: Zagreb index = {}, upper bound = {}",
+                 z1_cycle, upper_bound_cycle);
+
+        // Test on a star graph K_{1,5}
+        let star = Graph::star(6);
+
+        let z1_star = star.first_zagreb_index();
+        let upper_bound_star = star.zagreb_upper_bound();
+
+        // The Zagreb index should not exceed the upper bound
+        assert!(z1_star as f64 <= upper_bound_star,
+                "Zagreb index {} should not exceed upper bound {} for star graph",
+                z1_star, upper_bound_star);
+
+        println!("K_{{1,5}}: Zagreb index = {}, upper bound = {}",
+                 z1_star, upper_bound_star);
+
+        // Test on a bipartite graph K_{m,n}
+        let mut bipartite = Graph::new(6);
+        // Create K_{2,4} with vertices 0,1 connected to vertices 2,3,4,5
+        for i in 0..2 {
+            for j in 2..6 {
+                bipartite.add_edge(i, j).unwrap();
+            }
+        }
+
+        let z1_bipartite = bipartite.first_zagreb_index();
+        let upper_bound_bipartite = bipartite.zagreb_upper_bound();
+
+        // The Zagreb index should not exceed the upper bound
+        assert!(z1_bipartite as f64 <= upper_bound_bipartite,
+                "Zagreb index {} should not exceed upper bound {} for bipartite graph",
+                z1_bipartite, upper_bound_bipartite);
+
+        println!("K_{{2,4}}: Zagreb index = {}, upper bound = {}",
+                 z1_bipartite, upper_bound_bipartite);
+
+        // Test on a Petersen graph (known to have specific properties)
+        let petersen = Graph::petersen();
+
+        let z1_petersen = petersen.first_zagreb_index();
+        let upper_bound_petersen = petersen.zagreb_upper_bound();
+
+        // The Zagreb index should not exceed the upper bound
+        assert!(z1_petersen as f64 <= upper_bound_petersen,
+                "Zagreb index {} should not exceed upper bound {} for Petersen graph",
+                z1_petersen, upper_bound_petersen);
+
+        println!("Petersen: Zagreb index = {}, upper bound = {}",
+                 z1_petersen, upper_bound_petersen);
+    }
+
+    #[test]
+    fn test_graph_properties() {
+        // Test if the implementation correctly identifies various graph properties
+
+        // 1. Complete graph K_n
+        let complete5 = Graph::complete(5);
+
+        // Expected properties for K_5
+        let is_complete = complete5.is_complete();
+        let is_hamiltonian = complete5.is_likely_hamiltonian_with(false);
+        let is_traceable = complete5.is_likely_traceable_with(false);
+
+        println!("K_5: is_complete={}, is_hamiltonian={}, is_traceable={}",
+                 is_complete, is_hamiltonian, is_traceable);
+
+        assert!(is_complete, "K_5 should be identified as a complete graph");
+        assert!(is_hamiltonian, "K_5 should be identified as Hamiltonian");
+        assert!(is_traceable, "K_5 should be identified as traceable");
+
+        // 2. Cycle graph C_n
+        let cycle6 = Graph::cycle(6);
+
+        // Expected properties for C_6
+        let is_cycle = cycle6.is_cycle();
+        let cycle_hamiltonian = cycle6.is_likely_hamiltonian_with(false);
+        let cycle_traceable = cycle6.is_likely_traceable_with(false);
+
+        println!("C_6: is_cycle={}, is_hamiltonian={}, is_traceable={}",
+                 is_cycle, cycle_hamiltonian, cycle_traceable);
+
+        assert!(is_cycle, "C_6 should be identified as a cycle graph");
+        assert!(cycle_hamiltonian, "C_6 should be identified as Hamiltonian");
+        assert!(cycle_traceable, "C_6 should be identified as traceable");
+
+        // 3. Path graph P_n
+        let path5 = Graph::path(5);
+
+        // Expected properties for P_5
+        let is_path = path5.is_path();
+        let path_hamiltonian = path5.is_likely_hamiltonian_with(false);
+        let path_traceable = path5.is_likely_traceable_with(false);
+
+        println!("P_5: is_path={}, is_hamiltonian={}, is_traceable={}",
+                 is_path, path_hamiltonian, path_traceable);
+
+        assert!(is_path, "P_5 should be identified as a path graph");
+        assert!(!path_hamiltonian, "P_5 should not be identified as Hamiltonian");
+        assert!(path_traceable, "P_5 should be identified as traceable");
+
+        // 4. Star graph K_{1,n}
+        let star5 = Graph::star(5);
+
+        // Expected properties for K_{1,4}
+        let is_star = star5.is_star();
+        let star_hamiltonian = star5.is_likely_hamiltonian_with(false);
+        let star_traceable = star5.is_likely_traceable_with(false);
+
+        println!("K_{{1,4}}: is_star={}, is_hamiltonian={}, is_traceable={}",
+                 is_star, star_hamiltonian, star_traceable);
+
+        assert!(is_star, "K_{{1,4}} should be identified as a star graph");
+        assert!(!star_hamiltonian, "K_{{1,4}} should not be identified as Hamiltonian");
+        assert!(star_traceable, "K_{{1,4}} should be identified as traceable");
+
+        // 5. Petersen graph
+        let petersen = Graph::petersen();
+
+        // Expected properties for Petersen graph
+        let is_petersen = petersen.is_petersen();
+        let petersen_hamiltonian = petersen.is_likely_hamiltonian_with(false);
+        let petersen_traceable = petersen.is_likely_traceable_with(false);
+
+        println!("Petersen: is_petersen={}, is_hamiltonian={}, is_traceable={}",
+                 is_petersen, petersen_hamiltonian, petersen_traceable);
+
+        // The Petersen graph is a famous counterexample - it's 3-regular, 3-connected,
+        // but not Hamiltonian. It is, however, traceable.
+        assert!(is_petersen, "Petersen graph should be identified as such");
+
+        // If the implementation has special handling for the Petersen graph:
+        if is_petersen {
+            assert!(!petersen_hamiltonian, "Petersen graph should not be identified as Hamiltonian");
+            assert!(petersen_traceable, "Petersen graph should be identified as traceable");
+        }
+
+        // 6. Cube graph (Q_3)
+        let cube = Graph::from_edges(
+            8,
+            [
+                // Bottom face
+                (0, 1), (1, 2), (2, 3), (3, 0),
+                // Top face
+                (4, 5), (5, 6), (6, 7), (7, 4),
+                // Connecting edges
+                (0, 4), (1, 5), (2, 6), (3, 7),
+            ],
+        )
+        .unwrap();
+
+        // Expected properties for cube graph
+        let cube_hamiltonian = cube.is_likely_hamiltonian_with(false);
+        let cube_traceable = cube.is_likely_traceable_with(false);
+        let cube_z1 = cube.first_zagreb_index();
 
-        for v in 0..self.n_vertices {
-            if self.edges.get(&v).unwrap().len() != expected_degree {
-                return false;
-            }
-        }
+        println!("Cube graph: Zagreb index={}, is_hamiltonian={}, is_traceable={}",
+                 cube_z1, cube_hamiltonian, cube_traceable);
 
-        // Double-check: the number of edges should be n*(n-1)/2
-        let expected_edge_count = self.n_vertices * (self.n_vertices - 1) / 2;
-        if self.n_edges != expected_edge_count {
-            return false;
-        }
+        // The cube graph is known to be Hamiltonian
+        // Note: We don't enforce this if the implementation approaches it differently
+        assert_eq!(cube_z1, 72, "Cube graph Zagreb index should be 8 * 3² = 72");
 
-        true
+        // Print whether the implementation identifies it as Hamiltonian
+        println!("Implementation identifies cube graph as Hamiltonian: {}", cube_hamiltonian);
     }
 
-    /// Check if the graph is a cycle graph (each vertex has exactly 2 neighbors)
-    fn is_cycle(&self) -> bool {
-        // For a cycle, every vertex has degree 2
-        self.min_degree() == 2 && self.max_degree() == 2 && self.n_edges == self.n_vertices
+    #[test]
+    fn test_wiener_index() {
+        // Path P4: distances 1+2+3 + 1+2 + 1 = 10
+        let path4 = Graph::path(4);
+        assert_eq!(path4.wiener_index(), Some(10));
+
+        // Cycle C6: each vertex sees distances 1,1,2,2,3 => 6 * 9 / 2 = 27
+        let cycle6 = Graph::cycle(6);
+        assert_eq!(cycle6.wiener_index(), Some(27));
+
+        // Disconnected graphs have no Wiener index
+        let disconnected = Graph::from_edges(4, [(0, 1), (2, 3)]).unwrap();
+        assert_eq!(disconnected.wiener_index(), None);
     }
 
-    /// Check if the graph is a star graph (one central vertex connected to all others)
-    fn is_star(&self) -> bool {
-        if self.n_vertices <= 1 {
-            return false;
-        }
+    #[test]
+    fn test_max_leaf_spanning_tree_approx() {
+        // A hub connected to everyone, plus a few edges between the spokes
+        let mut graph = Graph::star(10);
+        graph.add_edge(1, 2).unwrap();
+        graph.add_edge(3, 4).unwrap();
+        graph.add_edge(5, 6).unwrap();
 
-        // Count vertices of degree 1
-        let degree_one_count = (0..self.n_vertices)
-            .filter(|&v| self.edges.get(&v).unwrap().len() == 1)
-            .count();
+        let tree = graph.max_leaf_spanning_tree_approx().unwrap();
+        assert_eq!(tree.vertex_count(), 10);
+        assert_eq!(tree.edge_count(), 9, "A spanning tree on 10 vertices has 9 edges");
 
-        // Count vertices of degree n-1
-        let degree_n_minus_1_count = (0..self.n_vertices)
-            .filter(|&v| self.edges.get(&v).unwrap().len() == self.n_vertices - 1)
-            .count();
+        // The hub should be internal and every other vertex should be a leaf
+        assert!(tree.degree(0).unwrap() > 1, "Hub should be an internal vertex");
+        let leaves = (0..10).filter(|&v| tree.degree(v).unwrap() == 1).count();
+        assert_eq!(leaves, 9, "All non-hub vertices should be leaves");
 
-        // A star has exactly one vertex with degree n-1 and n-1 vertices with degree 1
-        degree_one_count == self.n_vertices - 1 && degree_n_minus_1_count == 1
+        // Disconnected graphs have no spanning tree
+        let disconnected = Graph::from_edges(4, [(0, 1), (2, 3)]).unwrap();
+        assert!(disconnected.max_leaf_spanning_tree_approx().is_none());
     }
 
-    /// Check if the graph is a path graph (a tree with exactly 2 leaves)
-    fn is_path(&self) -> bool {
-        // For a path, we have exactly n-1 edges
-        if self.n_edges != self.n_vertices - 1 {
-            return false;
-        }
+    #[test]
+    fn test_paper_theorem_report() {
+        let petersen = Graph::petersen();
 
-        // A path has exactly 2 vertices with degree 1, and the rest have degree 2
-        let degree_one_count = (0..self.n_vertices)
-            .filter(|&v| self.edges.get(&v).unwrap().len() == 1)
-            .count();
+        let report = petersen.paper_theorem_report();
 
-        let degree_two_count = (0..self.n_vertices)
-            .filter(|&v| self.edges.get(&v).unwrap().len() == 2)
-            .count();
+        let n = petersen.vertex_count();
+        let e = petersen.edge_count();
+        let delta = petersen.min_degree();
+        let delta_max = petersen.max_degree();
+        let z1 = petersen.first_zagreb_index();
 
-        degree_one_count == 2 && degree_two_count == self.n_vertices - 2
+        // Theorem 1 with k = 2
+        let k = 2;
+        let part3 = ((n - k - 1) as f64).sqrt() - (delta as f64).sqrt();
+        let threshold1 = ((n - k - 1) as f64 * (delta_max * delta_max) as f64
+            + (e * e) as f64 / (k + 1) as f64
+            + part3 * part3 * e as f64)
+            .ceil() as usize;
+
+        // Theorem 2 with k = 1
+        let k = 1;
+        let part3 = ((n - k - 2) as f64).sqrt() - (delta as f64).sqrt();
+        let threshold2 = ((n - k - 2) as f64 * (delta_max * delta_max) as f64
+            + (e * e) as f64 / (k + 2) as f64
+            + part3 * part3 * e as f64)
+            .ceil() as usize;
+
+        assert_eq!(report.zagreb_index, 90);
+        assert_eq!(report.zagreb_index, z1);
+        assert_eq!(report.hamiltonian_threshold, threshold1);
+        assert_eq!(report.hamiltonian_threshold_met, z1 >= threshold1);
+        assert_eq!(report.traceable_threshold, threshold2);
+        assert_eq!(report.traceable_threshold_met, z1 >= threshold2);
+        assert_eq!(report.zagreb_upper_bound, petersen.zagreb_upper_bound());
+        assert!(report.zagreb_index as f64 <= report.zagreb_upper_bound);
     }
 
-    /// Calculate upper bound on Zagreb index using Theorem 3 from the paper
-    pub fn zagreb_upper_bound(&self) -> f64 {
-        let beta = self.independence_number_approx();
-        let delta = self.min_degree();
-        let n = self.n_vertices;
-        let e = self.n_edges;
-        let delta_max = self.max_degree();
+    #[test]
+    fn test_all_graphs() {
+        // 4 vertices have 6 possible edges, so there are 2^6 = 64 labeled graphs
+        let graphs: Vec<Graph> = Graph::all_graphs(4).collect();
+        assert_eq!(graphs.len(), 64);
 
-        // Apply Theorem 3 from the paper
-        let part1 = (n - beta) * delta_max * delta_max;
-        let part2 = (e * e) as f64 / beta as f64;
-        let part3 = ((n - beta) as f64).sqrt() - (delta as f64).sqrt();
-        let part3_squared = part3 * part3;
+        let mut total_edges = 0;
+        for graph in &graphs {
+            assert_eq!(graph.vertex_count(), 4);
 
-        part1 as f64 + part2 + part3_squared * e as f64
+            // The Zagreb index is the sum of squared degrees
+            let sum_of_squares: usize = (0..4).map(|v| graph.degree(v).unwrap().pow(2)).sum();
+            assert_eq!(graph.first_zagreb_index(), sum_of_squares);
+
+            total_edges += graph.edge_count();
+        }
+
+        // Each edge appears in exactly half of the graphs
+        assert_eq!(total_edges, 6 * 32);
+
+        assert_eq!(Graph::all_graphs(0).count(), 1);
+        assert_eq!(Graph::all_graphs(1).count(), 1);
     }
 
-    /// Get the number of vertices
-    pub fn vertex_count(&self) -> usize {
-        self.n_vertices
+    #[test]
+    fn test_edge_connectivity() {
+        // Cycle: removing any two edges disconnects it
+        let cycle = Graph::cycle(6);
+        assert_eq!(cycle.edge_connectivity(), 2);
+
+        // Complete graph K_n: λ = n - 1
+        let complete = Graph::complete(5);
+        assert_eq!(complete.edge_connectivity(), 4);
+
+        // Tree: every edge is a bridge
+        let tree = Graph::from_edges(6, [(0, 1), (0, 2), (1, 3), (1, 4), (2, 5)]).unwrap();
+        assert_eq!(tree.edge_connectivity(), 1);
+
+        // Disconnected and trivial graphs
+        let disconnected = Graph::from_edges(4, [(0, 1), (2, 3)]).unwrap();
+        assert_eq!(disconnected.edge_connectivity(), 0);
+        assert_eq!(Graph::new(1).edge_connectivity(), 0);
     }
 
-    /// Get the number of edges
-    pub fn edge_count(&self) -> usize {
-        self.n_edges
+    #[test]
+    fn test_to_dot() {
+        let graph = Graph::complete(2);
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("graph G {"));
+        assert!(dot.ends_with('}'));
+        assert!(dot.contains("0 -- 1"));
+        assert!(!dot.contains("1 -- 0"), "Each edge should appear only once");
+
+        // Isolated vertices are still declared
+        let sparse = Graph::from_edges(3, [(0, 1)]).unwrap();
+        let dot = sparse.to_dot();
+        assert!(dot.contains("  2;\n"));
+        assert_eq!(dot.matches("--").count(), 1);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use rand::thread_rng;
-    use super::*;
+    #[test]
+    fn test_from_biadjacency() {
+        // A fully-ones biadjacency matrix yields the complete bipartite graph K_{2,3}
+        let matrix = vec![vec![1, 1, 1], vec![1, 1, 1]];
+        let graph = Graph::from_biadjacency(2, 3, &matrix).unwrap();
+        assert_eq!(graph.vertex_count(), 5);
+        assert_eq!(graph.edge_count(), 6);
+        assert_eq!(graph.degree(0).unwrap(), 3);
+        assert_eq!(graph.degree(1).unwrap(), 3);
+        for v in 2..5 {
+            assert_eq!(graph.degree(v).unwrap(), 2);
+        }
+
+        // Zero entries add no edges
+        let sparse = Graph::from_biadjacency(2, 2, &[vec![1, 0], vec![0, 1]]).unwrap();
+        assert_eq!(sparse.edge_count(), 2);
+
+        // Malformed matrices are rejected
+        assert!(Graph::from_biadjacency(3, 3, &matrix).is_err());
+        assert!(Graph::from_biadjacency(2, 3, &[vec![1, 1, 1], vec![1, 1]]).is_err());
+        assert!(Graph::from_biadjacency(1, 2, &[vec![1, 2]]).is_err());
+    }
 
     #[test]
-    fn test_k_connectivity_exact_vs_approx() {
-        // Test on various graph types
+    fn test_edge_list_round_trip() {
+        let graph = Graph::from_edges(6, [(0, 1), (4, 2), (1, 5), (3, 0)]).unwrap();
 
-        // 1. Complete graph (should be (n-1)-connected)
-        let mut complete = Graph::new(6);
-        for i in 0..5 {
-            for j in (i + 1)..6 {
-                complete.add_edge(i, j).unwrap();
+        let edges = graph.edge_list();
+        assert_eq!(edges, vec![(0, 1), (0, 3), (1, 5), (2, 4)]);
+
+        let text: String = edges.iter().map(|(u, v)| format!("{} {}\n", u, v)).collect();
+        let parsed = Graph::from_edge_list(6, &text).unwrap();
+        assert_eq!(parsed.vertex_count(), 6);
+        assert_eq!(parsed.edge_list(), edges);
+
+        // Comments, blank lines and several pairs per line are accepted
+        let text = "# saved topology\n0 1  2 3\n\n4 5 # trailing comment\n";
+        let parsed = Graph::from_edge_list(6, text).unwrap();
+        assert_eq!(parsed.edge_list(), vec![(0, 1), (2, 3), (4, 5)]);
+
+        // Invalid input is reported
+        assert_eq!(
+            Graph::from_edge_list(3, "0 7").unwrap_err(),
+            GraphError::VertexOutOfBounds(7)
+        );
+        assert_eq!(Graph::from_edge_list(3, "1 1").unwrap_err(), GraphError::SelfLoop(1));
+        assert!(Graph::from_edge_list(3, "0 1 2").is_err());
+        assert!(Graph::from_edge_list(3, "0 x").is_err());
+    }
+
+    #[test]
+    fn test_adjacency_matrix_round_trip() {
+        let petersen = Graph::petersen();
+
+        let matrix = petersen.to_adjacency_matrix();
+        assert_eq!(matrix.len(), 10);
+        for (i, row) in matrix.iter().enumerate() {
+            assert_eq!(row.len(), 10);
+            assert_eq!(row[i], 0);
+            assert_eq!(row.iter().map(|&x| x as usize).sum::<usize>(), 3);
+            for (j, &entry) in row.iter().enumerate() {
+                assert_eq!(entry, matrix[j][i], "Matrix should be symmetric");
             }
         }
 
-        // Verify that is_complete works correctly
-        assert!(
-            complete.is_complete(),
-            "Complete graph detection should work"
+        let restored = Graph::from_adjacency_matrix(&matrix).unwrap();
+        assert_eq!(restored.vertex_count(), 10);
+        assert_eq!(restored.edge_count(), 15);
+        assert_eq!(restored.edge_list(), petersen.edge_list());
+
+        // Invalid matrices are rejected
+        assert_eq!(
+            Graph::from_adjacency_matrix(&[vec![1, 0], vec![0, 0]]).unwrap_err(),
+            GraphError::SelfLoop(0)
         );
+        assert!(Graph::from_adjacency_matrix(&[vec![0, 1], vec![0, 0]]).is_err());
+        assert!(Graph::from_adjacency_matrix(&[vec![0, 1], vec![1]]).is_err());
+    }
 
-        for k in 1..=5 {
-            assert_eq!(
-                complete.is_k_connected_exact(k),
-                true,
-                "Complete graph (n=6) should be {}-connected with exact algorithm",
-                k
-            );
+    #[test]
+    fn test_hamiltonian_path_between() {
+        let path = Graph::path(5);
+
+        // Only the true endpoints admit a Hamiltonian path
+        assert_eq!(path.hamiltonian_path_between(0, 4), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(path.hamiltonian_path_between(4, 0), Some(vec![4, 3, 2, 1, 0]));
+        assert_eq!(path.hamiltonian_path_between(1, 4), None);
+        assert_eq!(path.hamiltonian_path_between(0, 3), None);
+        assert_eq!(path.hamiltonian_path_between(1, 3), None);
+        assert_eq!(path.hamiltonian_path_between(0, 5), None);
+
+        // In a cycle every pair of adjacent vertices are endpoints of a Hamiltonian path
+        let cycle = Graph::cycle(6);
+        let found = cycle.hamiltonian_path_between(2, 3).unwrap();
+        assert_eq!(found.len(), 6);
+        assert_eq!(found[0], 2);
+        assert_eq!(found[5], 3);
+        assert_eq!(cycle.hamiltonian_path_between(0, 3), None);
+    }
 
-            assert_eq!(
-                complete.is_k_connected_approx(k),
-                true,
-                "Complete graph (n=6) should be {}-connected with approximate algorithm",
-                k
-            );
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut cycle = Graph::cycle(6);
+
+        let json = serde_json::to_string(&cycle).unwrap();
+        assert!(json.contains("\"n_vertices\":6"));
+
+        let restored: Graph = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, cycle);
+
+        // Invalid edges are rejected on deserialization
+        let invalid = r#"{"n_vertices":2,"edges":[[0,5]]}"#;
+        assert!(serde_json::from_str::<Graph>(invalid).is_err());
+
+        // Unweighted graphs omit the weights field, weighted ones keep their weights
+        assert!(!json.contains("weights"));
+        cycle.add_weighted_edge(0, 1, 2.5).unwrap();
+        let json = serde_json::to_string(&cycle).unwrap();
+        let restored: Graph = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.edge_weight(1, 0), Some(2.5));
+        assert_eq!(restored, cycle);
+    }
 
-            // Also test the wrapper function
-            assert_eq!(
-                complete.is_k_connected(k, true),
-                true,
-                "Complete graph (n=6) should be {}-connected with wrapper (exact)",
-                k
-            );
+    #[test]
+    fn test_graph_equality() {
+        let a = Graph::path(4);
 
-            assert_eq!(
-                complete.is_k_connected(k, false),
-                true,
-                "Complete graph (n=6) should be {}-connected with wrapper (approx)",
-                k
-            );
+        // Same edges added in a different order and orientation
+        let b = Graph::path(4);
+        assert_eq!(a, b);
+
+        // Different edge sets are not equal
+        let c = Graph::from_edges(4, [(0, 1), (1, 2), (3, 0)]).unwrap();
+        assert_ne!(a, c);
+
+        // Different vertex counts are not equal
+        let d = Graph::from_edges(5, [(0, 1), (1, 2), (2, 3)]).unwrap();
+        assert_ne!(a, d);
+
+        assert_eq!(Graph::new(3), Graph::new(3));
+    }
+
+    #[test]
+    fn test_neighborhood_subgraph() {
+        // In a complete graph the radius-1 neighborhood of any vertex is the whole graph
+        let complete = Graph::complete(5);
+        let neighborhood = complete.neighborhood_subgraph(2, 1);
+        assert_eq!(neighborhood.vertex_count(), 5);
+        assert!(neighborhood.is_complete());
+
+        // Around the middle of a path, radius 1 gives P3 and radius 0 a single vertex
+        let path = Graph::path(7);
+        let neighborhood = path.neighborhood_subgraph(3, 1);
+        assert_eq!(neighborhood.vertex_count(), 3);
+        assert_eq!(neighborhood.edge_list(), vec![(0, 1), (1, 2)]);
+        assert_eq!(path.neighborhood_subgraph(3, 0), Graph::new(1));
+        assert_eq!(path.neighborhood_subgraph(0, 10), path);
+
+        assert_eq!(path.neighborhood_subgraph(7, 1).vertex_count(), 0);
+    }
+
+    #[test]
+    fn test_regularity_gap() {
+        let petersen = Graph::petersen();
+        assert_eq!(petersen.regularity_gap(), 0);
+
+        let cycle = Graph::cycle(6);
+        assert_eq!(cycle.regularity_gap(), 0);
+
+        // Star K_{1,n-1}: center degree n-1, leaves degree 1
+        let n = 7;
+        let star = Graph::star(n);
+        assert_eq!(star.regularity_gap(), n - 2);
+
+        assert_eq!(Graph::new(0).regularity_gap(), 0);
+    }
+
+    #[test]
+    fn test_complement() {
+        // The complement of a complete graph is edgeless and vice versa
+        let complete = Graph::complete(5);
+        assert_eq!(complete.complement(), Graph::new(5));
+        assert_eq!(Graph::new(5).complement(), complete);
+
+        // The complement of C5 is again a 5-cycle
+        let cycle = Graph::cycle(5);
+        let cycle_complement = cycle.complement();
+        assert_eq!(cycle_complement.edge_count(), 5);
+        assert!(cycle_complement.is_cycle());
+
+        // Taking the complement twice gives back the original graph
+        let star = Graph::star(6);
+        let path = Graph::path(6);
+        for graph in [&complete, &cycle, &star, &path] {
+            assert_eq!(&graph.complement().complement(), graph);
         }
+    }
 
-        // A complete graph with n vertices is (n-1)-connected but not n-connected
-        // Test the wrapper function first (most important to users)
-        assert_eq!(
-            complete.is_k_connected(6, false),
-            false,
-            "Complete graph (n=6) should not be 6-connected with wrapper (approx)"
-        );
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_analysis_json() {
+        let cycle = Graph::cycle(5);
+
+        let json = cycle.to_analysis_json();
+        let document: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(document["schema_version"], ANALYSIS_SCHEMA_VERSION);
+
+        // The graph portion round-trips
+        let graph: Graph = serde_json::from_value(document["graph"].clone()).unwrap();
+        assert_eq!(graph, cycle);
+
+        // The metrics are all present
+        let report = document["report"].as_object().unwrap();
+        for key in [
+            "vertex_count",
+            "edge_count",
+            "min_degree",
+            "max_degree",
+            "first_zagreb_index",
+            "wiener_index",
+            "edge_connectivity",
+            "independence_number",
+            "is_likely_hamiltonian",
+            "is_likely_traceable",
+            "zagreb_upper_bound",
+        ] {
+            assert!(report.contains_key(key), "Missing metric {}", key);
+        }
+        assert_eq!(report["first_zagreb_index"], 20);
 
-        // Then test both individual functions
+        let parsed: GraphReport = serde_json::from_value(document["report"].clone()).unwrap();
+        assert_eq!(parsed, cycle.report());
+    }
+
+    #[test]
+    fn test_induced_subgraph() {
+        let complete = Graph::complete(5);
+
+        // Three mutually adjacent vertices of K5 induce a triangle
+        let (triangle, mapping) = complete.induced_subgraph(&[4, 1, 3]).unwrap();
+        assert_eq!(triangle.vertex_count(), 3);
+        assert_eq!(triangle.edge_count(), 3);
+        assert!(triangle.is_cycle());
+        assert_eq!(mapping, vec![4, 1, 3]);
+
+        // Only edges between selected vertices are kept
+        let path = Graph::path(5);
+        let (subgraph, mapping) = path.induced_subgraph(&[0, 2, 3]).unwrap();
+        assert_eq!(subgraph.edge_list(), vec![(1, 2)]);
+        assert_eq!(mapping[1], 2);
+        assert_eq!(mapping[2], 3);
+
+        // Invalid selections are rejected
         assert_eq!(
-            complete.is_k_connected_approx(6),
-            false,
-            "Complete graph (n=6) should not be 6-connected with approximate algorithm"
+            path.induced_subgraph(&[0, 5]).unwrap_err(),
+            GraphError::VertexOutOfBounds(5)
         );
-
         assert_eq!(
-            complete.is_k_connected_exact(6),
-            false,
-            "Complete graph (n=6) should not be 6-connected with exact algorithm"
+            path.induced_subgraph(&[1, 2, 1]).unwrap_err(),
+            GraphError::DuplicateVertex(1)
         );
+    }
 
-        // 2. Cycle graph (should be 2-connected but not 3-connected)
-        let mut cycle = Graph::new(5);
-        cycle.add_edge(0, 1).unwrap();
-        cycle.add_edge(1, 2).unwrap();
-        cycle.add_edge(2, 3).unwrap();
-        cycle.add_edge(3, 4).unwrap();
-        cycle.add_edge(4, 0).unwrap();
+    #[test]
+    fn test_min_degree_spanning_tree_approx() {
+        // Star plus a cycle through the leaves (a wheel with its hub at vertex 0)
+        let n = 9;
+        let mut graph = Graph::new(n);
+        for i in 1..n {
+            graph.add_edge(0, i).unwrap();
+            graph.add_edge(i, i % (n - 1) + 1).unwrap();
+        }
 
-        assert_eq!(
-            cycle.is_k_connected_exact(1),
-            true,
-            "Cycle graph should be 1-connected with exact algorithm"
-        );
+        let tree = graph.min_degree_spanning_tree_approx().unwrap();
+        assert_eq!(tree.vertex_count(), n);
+        assert_eq!(tree.edge_count(), n - 1);
+        assert!(tree.is_connected(), "Result should be a spanning tree");
+        for (u, v) in tree.edge_list() {
+            assert!(graph.edges[u].contains(&v), "Tree edges must exist in the graph");
+        }
 
-        assert_eq!(
-            cycle.is_k_connected_exact(2),
-            true,
-            "Cycle graph should be 2-connected with exact algorithm"
-        );
+        // The hub should not keep all n-1 spokes when the rim offers alternatives
+        assert!(tree.degree(0).unwrap() < n - 1);
+        assert!(tree.max_degree() <= 3);
 
-        assert_eq!(
-            cycle.is_k_connected_exact(3),
-            false,
-            "Cycle graph should not be 3-connected with exact algorithm"
-        );
+        // A pure star has no alternative
+        let star = Graph::star(5);
+        assert_eq!(star.min_degree_spanning_tree_approx().unwrap(), star);
 
-        // Both algorithms should agree on these simple cases
-        assert_eq!(
-            cycle.is_k_connected_approx(1),
-            cycle.is_k_connected_exact(1),
-            "Approximation and exact algorithms should agree for cycle graph with k=1"
-        );
+        let disconnected = Graph::from_edges(4, [(0, 1)]).unwrap();
+        assert!(disconnected.min_degree_spanning_tree_approx().is_none());
+    }
 
-        assert_eq!(
-            cycle.is_k_connected_approx(2),
-            cycle.is_k_connected_exact(2),
-            "Approximation and exact algorithms should agree for cycle graph with k=2"
-        );
+    #[test]
+    fn test_connected_components() {
+        // Two disjoint triangles
+        let graph = Graph::from_edges(6, [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]).unwrap();
+
+        let components = graph.connected_components();
+        assert_eq!(components, vec![vec![0, 1, 2], vec![3, 4, 5]]);
 
+        // An edgeless graph has n singleton components
+        let empty = Graph::new(4);
         assert_eq!(
-            cycle.is_k_connected_approx(3),
-            cycle.is_k_connected_exact(3),
-            "Approximation and exact algorithms should agree for cycle graph with k=3"
+            empty.connected_components(),
+            vec![vec![0], vec![1], vec![2], vec![3]]
         );
 
-        // 3. Path graph (should be 1-connected but not 2-connected)
-        let mut path = Graph::new(5);
-        path.add_edge(0, 1).unwrap();
-        path.add_edge(1, 2).unwrap();
-        path.add_edge(2, 3).unwrap();
-        path.add_edge(3, 4).unwrap();
+        assert!(Graph::new(0).connected_components().is_empty());
+    }
 
-        assert_eq!(
-            path.is_k_connected_exact(1),
-            true,
-            "Path graph should be 1-connected with exact algorithm"
-        );
+    #[test]
+    fn test_is_vertex_transitive() {
+        let petersen = Graph::petersen();
+        assert_eq!(petersen.is_vertex_transitive(10), Some(true));
 
-        assert_eq!(
-            path.is_k_connected_exact(2),
-            false,
-            "Path graph should not be 2-connected with exact algorithm"
-        );
+        let cycle = Graph::cycle(7);
+        assert_eq!(cycle.is_vertex_transitive(10), Some(true));
 
-        // Both algorithms should agree on these simple cases
-        assert_eq!(
-            path.is_k_connected_approx(1),
-            path.is_k_connected_exact(1),
-            "Approximation and exact algorithms should agree for path graph with k=1"
-        );
+        let path = Graph::path(5);
+        assert_eq!(path.is_vertex_transitive(10), Some(false));
 
-        assert_eq!(
-            path.is_k_connected_approx(2),
-            path.is_k_connected_exact(2),
-            "Approximation and exact algorithms should agree for path graph with k=2"
-        );
+        // A regular graph that is not vertex-transitive: disjoint triangle and square
+        let mixed =
+            Graph::from_edges(7, [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 6), (6, 3)]).unwrap();
+        assert_eq!(mixed.regularity_gap(), 0);
+        assert_eq!(mixed.is_vertex_transitive(10), Some(false));
 
-        // 4. Test on a small Petersen-like graph (should be 3-connected but not 4-connected)
-        // Using a smaller test graph to avoid long test times
-        let mut test_graph = Graph::new(6);
-        test_graph.add_edge(0, 1).unwrap();
-        test_graph.add_edge(1, 2).unwrap();
-        test_graph.add_edge(2, 0).unwrap();
-        test_graph.add_edge(3, 4).unwrap();
-        test_graph.add_edge(4, 5).unwrap();
-        test_graph.add_edge(5, 3).unwrap();
-        test_graph.add_edge(0, 3).unwrap();
-        test_graph.add_edge(1, 4).unwrap();
-        test_graph.add_edge(2, 5).unwrap();
+        // Graphs above the size limit are not checked
+        assert_eq!(petersen.is_vertex_transitive(9), None);
+    }
+
+    #[test]
+    fn test_is_connected() {
+        // The empty graph and a single vertex are trivially connected
+        assert!(Graph::new(0).is_connected());
+        assert!(Graph::new(1).is_connected());
+
+        // Two isolated vertices are not
+        assert!(!Graph::new(2).is_connected());
+
+        // Two components
+        let mut graph = Graph::from_edges(5, [(0, 1), (1, 2), (3, 4)]).unwrap();
+        assert!(!graph.is_connected());
+
+        graph.add_edge(2, 3).unwrap();
+        assert!(graph.is_connected());
+    }
+
+    #[test]
+    fn test_max_cut_approx() {
+        // Every edge of a complete bipartite graph crosses the optimal cut
+        let mut bipartite = Graph::new(7);
+        for i in 0..3 {
+            for j in 3..7 {
+                bipartite.add_edge(i, j).unwrap();
+            }
+        }
+        assert_eq!(bipartite.max_cut_approx(), 12);
+
+        // An even cycle is bipartite, an odd cycle loses one edge
+        let cycle6 = Graph::cycle(6);
+        assert_eq!(cycle6.max_cut_approx(), 6);
+
+        let cycle5 = Graph::cycle(5);
+        assert_eq!(cycle5.max_cut_approx(), 4);
+
+        // The 0.5-approximation guarantee holds on a dense graph
+        let complete = Graph::complete(6);
+        assert!(2 * complete.max_cut_approx() >= complete.edge_count());
+        assert_eq!(Graph::new(0).max_cut_approx(), 0);
+    }
+
+    #[test]
+    fn test_find_path_in_subgraph_short_adjacency() {
+        let graph = Graph::new(6);
+
+        // Only vertices 0, 1 and 2 have entries; 3, 4 and 5 are past the end of the slice
+        let sparse_edges: Vec<HashSet<usize>> = vec![
+            [1, 3].into_iter().collect(),
+            [0, 2].into_iter().collect(),
+            [1].into_iter().collect(),
+        ];
 
         assert_eq!(
-            test_graph.is_k_connected_exact(3),
-            true,
-            "Test graph should be 3-connected with exact algorithm"
+            graph.find_path_in_subgraph(&sparse_edges, 0, 2),
+            Some(vec![0, 1, 2])
         );
 
-        assert_eq!(
-            test_graph.is_k_connected_exact(4),
-            false,
-            "Test graph should not be 4-connected with exact algorithm"
+        // Searching through or towards a missing vertex must not panic
+        assert_eq!(graph.find_path_in_subgraph(&sparse_edges, 0, 5), None);
+        assert_eq!(graph.find_path_in_subgraph(&sparse_edges, 4, 0), None);
+    }
+
+    #[test]
+    fn test_erdos_renyi() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(7);
+
+        // Expected edge count for n=100, p=0.5 is 4950 * 0.5 = 2475 with standard deviation ~35
+        let graph = Graph::erdos_renyi(100, 0.5, &mut rng);
+        assert_eq!(graph.vertex_count(), 100);
+        let edges = graph.edge_count() as f64;
+        assert!(
+            (edges - 2475.0).abs() < 200.0,
+            "Edge count {} is far from the expected 2475",
+            edges
         );
+
+        // Extreme probabilities give the empty and complete graphs
+        assert_eq!(Graph::erdos_renyi(10, 0.0, &mut rng).edge_count(), 0);
+        assert!(Graph::erdos_renyi(10, 1.0, &mut rng).is_complete());
     }
 
     #[test]
-    fn test_find_path() {
-        // Simple path test on a line graph
-        let mut path_graph = Graph::new(5);
-        path_graph.add_edge(0, 1).unwrap();
-        path_graph.add_edge(1, 2).unwrap();
-        path_graph.add_edge(2, 3).unwrap();
-        path_graph.add_edge(3, 4).unwrap();
+    #[should_panic]
+    fn test_erdos_renyi_invalid_probability() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
 
-        // There should be a path from 0 to 4
-        let path = path_graph.find_path(0, 4);
-        assert!(path.is_some(), "Should find a path from 0 to 4");
+        let mut rng = StdRng::seed_from_u64(7);
+        Graph::erdos_renyi(10, 1.5, &mut rng);
+    }
 
-        let path_vertices = path.unwrap();
-        assert_eq!(path_vertices.len(), 5, "Path should visit 5 vertices");
-        assert_eq!(path_vertices[0], 0, "Path should start at vertex 0");
-        assert_eq!(path_vertices[4], 4, "Path should end at vertex 4");
+    #[test]
+    fn test_block_cut_tree() {
+        // Three triangles in a row, joined at cut vertices 2 and 4
+        let graph = Graph::from_edges(
+            7,
+            [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (4, 5), (5, 6), (6, 4)],
+        )
+        .unwrap();
+
+        assert_eq!(graph.articulation_points(), vec![2, 4]);
+
+        let mut blocks = graph.biconnected_components();
+        blocks.sort();
+        assert_eq!(blocks, vec![vec![0, 1, 2], vec![2, 3, 4], vec![4, 5, 6]]);
+
+        // The tree is the path block - 2 - block - 4 - block
+        let (tree, labels) = graph.block_cut_tree();
+        assert_eq!(tree.vertex_count(), 5);
+        assert_eq!(tree.edge_count(), 4);
+        assert!(tree.is_path());
+        assert_eq!(labels.len(), 5);
+        assert_eq!(labels[3], BlockCutNode::CutVertex(2));
+        assert_eq!(labels[4], BlockCutNode::CutVertex(4));
+
+        // The middle triangle is the block adjacent to both cut vertices
+        let middle = (0..3).find(|&b| tree.degree(b).unwrap() == 2).unwrap();
+        assert_eq!(labels[middle], BlockCutNode::Block(vec![2, 3, 4]));
+
+        // A biconnected graph is a single block with no cut vertices
+        let cycle = Graph::cycle(5);
+        let (tree, labels) = cycle.block_cut_tree();
+        assert_eq!(tree.vertex_count(), 1);
+        assert_eq!(labels, vec![BlockCutNode::Block(vec![0, 1, 2, 3, 4])]);
+
+        // In a path every edge is a block and every internal vertex a cut vertex
+        let path = Graph::path(4);
+        assert_eq!(path.articulation_points(), vec![1, 2]);
+        assert_eq!(path.biconnected_components().len(), 3);
+    }
 
-        // Test on a disconnected graph
-        let mut disconnected = Graph::new(5);
-        disconnected.add_edge(0, 1).unwrap();
-        disconnected.add_edge(1, 2).unwrap();
-        // No connection to vertices 3 and 4
+    #[test]
+    fn test_block_cut_tree_long_path() {
+        // Deep enough that a recursive DFS would overflow the stack
+        let n = 200_000;
+        let path = Graph::path(n);
+        assert_eq!(path.articulation_points(), (1..n - 1).collect::<Vec<_>>());
+        assert_eq!(path.biconnected_components().len(), n - 1);
+        assert_eq!(path.block_cut_tree().0.vertex_count(), 2 * n - 3);
+    }
 
-        let path = disconnected.find_path(0, 4);
-        assert!(
-            path.is_none(),
-            "Should not find a path in disconnected graph"
-        );
+    #[test]
+    fn test_grid() {
+        let (rows, cols) = (4, 5);
+        let grid = Graph::grid(rows, cols);
+        assert_eq!(grid.vertex_count(), rows * cols);
+        assert_eq!(grid.edge_count(), rows * (cols - 1) + cols * (rows - 1));
+
+        // 4 corners of degree 2, 2*(rows-2) + 2*(cols-2) border cells of degree 3, interior degree 4
+        let count = |d: usize| (0..rows * cols).filter(|&v| grid.degree(v).unwrap() == d).count();
+        assert_eq!(count(2), 4);
+        assert_eq!(count(3), 2 * (rows - 2) + 2 * (cols - 2));
+        assert_eq!(count(4), (rows - 2) * (cols - 2));
+
+        // Degenerate grids are paths
+        assert!(Graph::grid(1, 5).is_path());
+        assert_eq!(Graph::grid(0, 3).vertex_count(), 0);
+    }
 
-        // Test find_path_in_subgraph with custom edges
-        use std::collections::{HashMap, HashSet};
+    #[test]
+    fn test_effective_resistance() {
+        // A single edge is one unit resistor
+        let edge = Graph::complete(2);
+        assert!((edge.effective_resistance(0, 1).unwrap() - 1.0).abs() < 1e-9);
+
+        // Resistors in series add up along a path
+        let path = Graph::path(4);
+        assert!((path.effective_resistance(0, 3).unwrap() - 3.0).abs() < 1e-9);
+
+        // k parallel paths, each two unit resistors in series, give 2/k
+        for k in 1..=4 {
+            let mut parallel = Graph::new(k + 2);
+            for i in 0..k {
+                parallel.add_edge(0, i + 2).unwrap();
+                parallel.add_edge(i + 2, 1).unwrap();
+            }
+            let resistance = parallel.effective_resistance(0, 1).unwrap();
+            assert!(
+                (resistance - 2.0 / k as f64).abs() < 1e-9,
+                "Expected {} for {} parallel paths, got {}",
+                2.0 / k as f64,
+                k,
+                resistance
+            );
+        }
 
-        let mut custom_edges = HashMap::new();
-        for i in 0..5 {
-            custom_edges.insert(i, HashSet::new());
+        // Disconnected pairs and invalid vertices have no resistance
+        let disconnected = Graph::from_edges(4, [(0, 1), (2, 3)]).unwrap();
+        assert_eq!(disconnected.effective_resistance(0, 3), None);
+        assert_eq!(disconnected.effective_resistance(0, 4), None);
+        assert_eq!(disconnected.effective_resistance(2, 2), Some(0.0));
+    }
+
+    #[test]
+    fn test_strongly_regular_parameters() {
+        let petersen = Graph::petersen();
+        assert_eq!(petersen.strongly_regular_parameters(), Some((10, 3, 0, 1)));
+
+        // C5 is strongly regular with parameters (5, 2, 0, 1)
+        let cycle5 = Graph::cycle(5);
+        assert_eq!(cycle5.strongly_regular_parameters(), Some((5, 2, 0, 1)));
+
+        // C6 is regular but non-adjacent pairs have 0 or 2 common neighbors
+        let cycle6 = Graph::cycle(6);
+        assert_eq!(cycle6.strongly_regular_parameters(), None);
+
+        let path = Graph::path(5);
+        assert_eq!(path.strongly_regular_parameters(), None);
+    }
+
+    #[test]
+    fn test_greedy_hamiltonian_path() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let is_hamiltonian_path = |graph: &Graph, path: &[usize]| {
+            let distinct: HashSet<usize> = path.iter().cloned().collect();
+            path.len() == graph.vertex_count()
+                && distinct.len() == path.len()
+                && path
+                    .windows(2)
+                    .all(|pair| graph.edges[pair[0]].contains(&pair[1]))
+        };
+
+        // Dense random graphs are easily traceable
+        let mut rng = StdRng::seed_from_u64(11);
+        for _ in 0..5 {
+            let graph = Graph::erdos_renyi(60, 0.5, &mut rng);
+            let path = graph.greedy_hamiltonian_path().expect("heuristic should succeed");
+            assert!(is_hamiltonian_path(&graph, &path));
         }
 
-        // Create a different path: 0-2-4
-        custom_edges.get_mut(&0).unwrap().insert(2);
-        custom_edges.get_mut(&2).unwrap().insert(0);
-        custom_edges.get_mut(&2).unwrap().insert(4);
-        custom_edges.get_mut(&4).unwrap().insert(2);
+        // Paths, cycles and the Petersen graph
+        let path_graph = Graph::path(8);
+        let path = path_graph.greedy_hamiltonian_path().unwrap();
+        assert!(is_hamiltonian_path(&path_graph, &path));
 
-        let custom_path = path_graph.find_path_in_subgraph(&custom_edges, 0, 4);
-        assert!(custom_path.is_some(), "Should find a custom path");
+        let petersen = Graph::petersen();
+        if let Some(path) = petersen.greedy_hamiltonian_path() {
+            assert!(is_hamiltonian_path(&petersen, &path));
+        }
 
-        let custom_path_vertices = custom_path.unwrap();
-        assert_eq!(
-            custom_path_vertices.len(),
-            3,
-            "Custom path should visit 3 vertices"
-        );
-        assert_eq!(
-            custom_path_vertices[0], 0,
-            "Custom path should start at vertex 0"
-        );
-        assert_eq!(
-            custom_path_vertices[1], 2,
-            "Custom path should go through vertex 2"
-        );
-        assert_eq!(
-            custom_path_vertices[2], 4,
-            "Custom path should end at vertex 4"
-        );
+        // Stars with more than two leaves and disconnected graphs have no Hamiltonian path
+        let star = Graph::star(5);
+        assert_eq!(star.greedy_hamiltonian_path(), None);
+        assert_eq!(Graph::new(3).greedy_hamiltonian_path(), None);
     }
 
     #[test]
-    fn test_find_vertex_disjoint_paths() {
-        // Complete graph with 5 vertices
-        let mut complete = Graph::new(5);
-        for i in 0..4 {
-            for j in (i + 1)..5 {
-                complete.add_edge(i, j).unwrap();
+    fn test_dfs_tree_leaf_count() {
+        // From an endpoint of a path, the DFS tree is the path itself
+        let path = Graph::path(6);
+        assert_eq!(path.dfs_tree_leaf_count(0), 1);
+        // From an interior vertex, the search runs in both directions
+        assert_eq!(path.dfs_tree_leaf_count(2), 2);
+
+        // A star's DFS tree from the center has a leaf per outer vertex
+        let star = Graph::star(5);
+        assert_eq!(star.dfs_tree_leaf_count(0), 4);
+        assert_eq!(star.dfs_tree_leaf_count(1), 3);
+
+        // A DFS of a complete graph always walks a Hamiltonian path
+        let complete = Graph::complete(5);
+        assert_eq!(complete.dfs_tree_leaf_count(3), 1);
+
+        // Isolated and out-of-bounds start vertices
+        assert_eq!(Graph::new(3).dfs_tree_leaf_count(1), 1);
+        assert_eq!(path.dfs_tree_leaf_count(6), 0);
+    }
+
+    #[test]
+    fn test_wheel() {
+        for n in 4..9 {
+            let wheel = Graph::wheel(n);
+            assert_eq!(wheel.vertex_count(), n);
+            assert_eq!(wheel.edge_count(), 2 * (n - 1));
+            assert_eq!(wheel.degree(0).unwrap(), n - 1);
+            for v in 1..n {
+                assert_eq!(wheel.degree(v).unwrap(), 3);
+            }
+
+            let cycle = wheel.hamiltonian_cycle().expect("wheels are Hamiltonian");
+            assert_eq!(cycle.len(), n);
+            let distinct: HashSet<usize> = cycle.iter().cloned().collect();
+            assert_eq!(distinct.len(), n);
+            for i in 0..n {
+                let (u, v) = (cycle[i], cycle[(i + 1) % n]);
+                assert!(wheel.edges[u].contains(&v));
             }
         }
 
-        // In a complete graph K5, there are 4 vertex-disjoint paths between any two vertices
-        // (1 direct edge + 3 paths through other vertices)
-        let disjoint_paths = complete.find_vertex_disjoint_paths(0, 1);
-        assert_eq!(
-            disjoint_paths, 4,
-            "Complete graph K5 should have 4 vertex-disjoint paths between any two vertices"
-        );
+        // W4 is K4
+        assert!(Graph::wheel(4).is_complete());
+    }
 
-        // Cycle graph
-        let mut cycle = Graph::new(5);
-        cycle.add_edge(0, 1).unwrap();
-        cycle.add_edge(1, 2).unwrap();
-        cycle.add_edge(2, 3).unwrap();
-        cycle.add_edge(3, 4).unwrap();
-        cycle.add_edge(4, 0).unwrap();
+    #[test]
+    #[should_panic(expected = "A wheel needs at least 4 vertices")]
+    fn test_wheel_too_small() {
+        Graph::wheel(3);
+    }
 
-        // Should have 2 vertex-disjoint paths between any two non-adjacent vertices
-        let disjoint_paths = cycle.find_vertex_disjoint_paths(0, 2);
-        assert_eq!(
-            disjoint_paths, 2,
-            "Cycle graph should have 2 vertex-disjoint paths between any two non-adjacent vertices"
-        );
+    #[test]
+    fn test_hamiltonian_cycle() {
+        let petersen = Graph::petersen();
+        assert_eq!(petersen.hamiltonian_cycle(), None);
 
-        // Check adjacent vertices in cycle
-        let disjoint_paths_adj = cycle.find_vertex_disjoint_paths(0, 1);
-        assert_eq!(
-            disjoint_paths_adj, 2,
-            "Cycle graph should handle adjacent vertices correctly"
-        );
+        let mut path = Graph::path(4);
+        assert_eq!(path.hamiltonian_cycle(), None);
 
-        // Path graph
-        let mut path = Graph::new(5);
-        path.add_edge(0, 1).unwrap();
-        path.add_edge(1, 2).unwrap();
-        path.add_edge(2, 3).unwrap();
-        path.add_edge(3, 4).unwrap();
+        path.add_edge(3, 0).unwrap();
+        assert_eq!(path.hamiltonian_cycle(), Some(vec![0, 3, 2, 1]));
 
-        // Should have 1 vertex-disjoint path between end vertices
-        let disjoint_paths = path.find_vertex_disjoint_paths(0, 4);
-        assert_eq!(
-            disjoint_paths, 1,
-            "Path graph should have 1 vertex-disjoint path between end vertices"
-        );
+        let edge = Graph::complete(2);
+        assert_eq!(edge.hamiltonian_cycle(), None);
+    }
 
-        // Test on a small graph with 6 vertices
-        let mut test_graph = Graph::new(6);
-        test_graph.add_edge(0, 1).unwrap();
-        test_graph.add_edge(1, 2).unwrap();
-        test_graph.add_edge(2, 0).unwrap();
-        test_graph.add_edge(3, 4).unwrap();
-        test_graph.add_edge(4, 5).unwrap();
-        test_graph.add_edge(5, 3).unwrap();
-        test_graph.add_edge(0, 3).unwrap();
-        test_graph.add_edge(1, 4).unwrap();
-        test_graph.add_edge(2, 5).unwrap();
+    #[test]
+    fn test_seidel_matrix() {
+        let petersen = Graph::petersen();
+
+        let seidel = petersen.seidel_matrix();
+        for (i, row) in seidel.iter().enumerate() {
+            assert_eq!(row[i], 0);
+            for (j, &entry) in row.iter().enumerate() {
+                assert_eq!(entry, seidel[j][i]);
+                if i != j {
+                    let adjacent = petersen.edges[i].contains(&j);
+                    assert_eq!(entry, if adjacent { -1 } else { 1 });
+                }
+            }
+        }
 
-        // Test graph should have 3 vertex-disjoint paths between vertices 0 and 5
-        let disjoint_paths = test_graph.find_vertex_disjoint_paths(0, 5);
-        assert_eq!(
-            disjoint_paths, 3,
-            "Test graph should have 3 vertex-disjoint paths between vertices 0 and 5"
-        );
+        // The Petersen graph's Seidel spectrum is {-3^5, 3^5}
+        let spectrum = petersen.seidel_spectrum();
+        assert_eq!(spectrum.len(), 10);
+        for (i, eigenvalue) in spectrum.iter().enumerate() {
+            let expected = if i < 5 { -3.0 } else { 3.0 };
+            assert!((eigenvalue - expected).abs() < 1e-9, "eigenvalue {} was {}", i, eigenvalue);
+        }
+
+        // K_n has Seidel matrix I - J, with spectrum {1 - n, 1^(n-1)}
+        let complete = Graph::complete(4);
+        let spectrum = complete.seidel_spectrum();
+        assert!((spectrum[0] + 3.0).abs() < 1e-9);
+        assert!(spectrum[1..].iter().all(|x| (x - 1.0).abs() < 1e-9));
+
+        assert!(Graph::new(0).seidel_spectrum().is_empty());
     }
 
     #[test]
-    fn test_cycle_graph() {
-        // Create a cycle graph with 5 vertices (should be Hamiltonian)
-        let mut graph = Graph::new(5);
-        graph.add_edge(0, 1).unwrap();
-        graph.add_edge(1, 2).unwrap();
-        graph.add_edge(2, 3).unwrap();
-        graph.add_edge(3, 4).unwrap();
-        graph.add_edge(4, 0).unwrap();
+    fn test_vertex_connectivity_matrix() {
+        let complete = Graph::complete(6);
+        let matrix = complete.vertex_connectivity_matrix();
+        for (s, row) in matrix.iter().enumerate() {
+            for (t, &entry) in row.iter().enumerate() {
+                assert_eq!(entry, if s == t { 0 } else { 5 });
+            }
+        }
 
-        assert_eq!(graph.first_zagreb_index(), 20); // Each vertex has degree 2, so 5 * 2^2 = 20
-        assert_eq!(graph.min_degree(), 2);
-        assert_eq!(graph.max_degree(), 2);
-        assert_eq!(graph.edge_count(), 5);
+        // Two triangles sharing vertex 2: pairs across the cut vertex have one disjoint path
+        let bowtie =
+            Graph::from_edges(5, [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]).unwrap();
+        let matrix = bowtie.vertex_connectivity_matrix();
+        assert_eq!(matrix[0][1], 2);
+        assert_eq!(matrix[0][2], 2);
+        assert_eq!(matrix[0][3], 1);
+        assert_eq!(matrix[4][1], 1);
+
+        // The Petersen graph is 3-connected
+        let petersen = Graph::petersen();
+        let matrix = petersen.vertex_connectivity_matrix();
+        let minimum = (0..10)
+            .flat_map(|s| (0..10).filter(move |&t| t != s).map(move |t| (s, t)))
+            .map(|(s, t)| matrix[s][t])
+            .min();
+        assert_eq!(minimum, Some(3));
+
+        // Disconnected pairs have no paths
+        let matrix = Graph::new(3).vertex_connectivity_matrix();
+        assert!(matrix.iter().flatten().all(|&entry| entry == 0));
+    }
 
-        // A cycle is its own Hamiltonian cycle
-        assert!(graph.is_likely_hamiltonian(false));
-        assert!(graph.is_likely_traceable(false));
+    #[test]
+    fn test_average_degree_and_density() {
+        let complete = Graph::complete(5);
+        assert_eq!(complete.average_degree(), 4.0);
+        assert_eq!(complete.density(), 1.0);
+
+        let empty = Graph::new(5);
+        assert_eq!(empty.average_degree(), 0.0);
+        assert_eq!(empty.density(), 0.0);
+
+        let cycle = Graph::cycle(6);
+        assert_eq!(cycle.average_degree(), 2.0);
+        assert!((cycle.density() - 0.4).abs() < 1e-12);
+
+        // Graphs with fewer than 2 vertices have no possible edges
+        for n in 0..2 {
+            assert_eq!(Graph::new(n).average_degree(), 0.0);
+            assert_eq!(Graph::new(n).density(), 0.0);
+        }
     }
 
     #[test]
-    fn test_complete_graph() {
-        // Create a complete graph with 6 vertices (should be Hamiltonian)
-        let mut graph = Graph::new(6);
+    fn test_girth() {
+        let triangle = Graph::cycle(3);
+        assert_eq!(triangle.girth(), Some(3));
+
+        let mut c6 = Graph::cycle(6);
+        assert_eq!(c6.girth(), Some(6));
+
+        // A chord splits C6 into two squares
+        c6.add_edge(0, 3).unwrap();
+        assert_eq!(c6.girth(), Some(4));
+
+        let mut tree = Graph::new(7);
+        for i in 1..7 {
+            tree.add_edge(i, (i - 1) / 2).unwrap();
+        }
+        assert_eq!(tree.girth(), None);
+        assert_eq!(Graph::new(0).girth(), None);
+
+        let petersen = Graph::petersen();
+        assert_eq!(petersen.girth(), Some(5));
+        assert!(petersen.is_petersen());
+
+        // The 5-prism is 3-regular on 10 vertices but has girth 4
+        let mut prism = Graph::new(10);
         for i in 0..5 {
-            for j in (i + 1)..6 {
+            prism.add_edge(i, (i + 1) % 5).unwrap();
+            prism.add_edge(i, i + 5).unwrap();
+            prism.add_edge(i + 5, (i + 1) % 5 + 5).unwrap();
+        }
+        assert_eq!(prism.girth(), Some(4));
+        assert!(!prism.is_petersen());
+    }
+
+    #[test]
+    fn test_max_clique() {
+        let complete = Graph::complete(5);
+        assert_eq!(complete.max_clique(), vec![0, 1, 2, 3, 4]);
+
+        // The Petersen graph is triangle-free
+        let petersen = Graph::petersen();
+        let clique = petersen.max_clique();
+        assert_eq!(clique.len(), 2);
+        assert!(petersen.edges[clique[0]].contains(&clique[1]));
+
+        // A K4 attached to a cycle by a single edge
+        let mut graph = Graph::new(9);
+        for i in 5..9 {
+            for j in (i + 1)..9 {
                 graph.add_edge(i, j).unwrap();
             }
         }
+        for i in 0..5 {
+            graph.add_edge(i, (i + 1) % 5).unwrap();
+        }
+        graph.add_edge(0, 5).unwrap();
+        assert_eq!(graph.max_clique(), vec![5, 6, 7, 8]);
 
-        // Each vertex has degree 5, so 6 * 5^2 = 150
-        assert_eq!(graph.first_zagreb_index(), 150);
-        assert_eq!(graph.min_degree(), 5);
-        assert_eq!(graph.max_degree(), 5);
-        assert_eq!(graph.edge_count(), 15);
-
-        // Complete graphs with n > 2 are always Hamiltonian
-        assert!(graph.is_likely_hamiltonian(false));
-        assert!(graph.is_likely_traceable(false));
+        // Without edges, a clique is a single vertex
+        assert_eq!(Graph::new(3).max_clique().len(), 1);
+        assert!(Graph::new(0).max_clique().is_empty());
     }
 
     #[test]
-    fn test_star_graph() {
-        // Create a star graph with 5 vertices (center and 4 leaves)
-        // Star graphs are not Hamiltonian for n > 3
-        let mut graph = Graph::new(5);
-        graph.add_edge(0, 1).unwrap();
-        graph.add_edge(0, 2).unwrap();
-        graph.add_edge(0, 3).unwrap();
-        graph.add_edge(0, 4).unwrap();
+    fn test_independence_number_exact() {
+        let petersen = Graph::petersen();
+        assert_eq!(petersen.independence_number_exact(), 4);
 
-        // Center has degree 4, leaves have degree 1, so 4^2 + 4*1^2 = 20
-        assert_eq!(graph.first_zagreb_index(), 20);
-        assert_eq!(graph.min_degree(), 1);
-        assert_eq!(graph.max_degree(), 4);
-        assert_eq!(graph.edge_count(), 4);
+        let p5 = Graph::path(5);
+        assert_eq!(p5.independence_number_exact(), 3);
 
-        // Star graphs with 5 vertices are not Hamiltonian
-        assert!(!graph.is_likely_hamiltonian(false));
-        // But they are traceable
-        assert!(graph.is_likely_traceable(false));
+        // The exact value is an upper bound for the greedy approximation
+        for graph in [&petersen, &p5] {
+            assert!(graph.independence_number_approx() <= graph.independence_number_exact());
+        }
+
+        let complete = Graph::complete(4);
+        assert_eq!(complete.independence_number_exact(), 1);
+        assert_eq!(Graph::new(4).independence_number_exact(), 4);
+        assert_eq!(Graph::new(0).independence_number_exact(), 0);
     }
 
     #[test]
-    fn test_petersen_graph() {
-        // Create the Petersen graph (10 vertices, 3-regular, non-Hamiltonian)
-        let mut graph = Graph::new(10);
+    fn test_weighted_zagreb_index() {
+        let mut unweighted = Graph::new(5);
+        let mut weighted = Graph::new(5);
+        for &(u, v) in &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (0, 2)] {
+            unweighted.add_edge(u, v).unwrap();
+            weighted.add_weighted_edge(u, v, 1.0).unwrap();
+        }
 
-        // Add outer cycle edges (pentagon)
-        graph.add_edge(0, 1).unwrap();
-        graph.add_edge(1, 2).unwrap();
-        graph.add_edge(2, 3).unwrap();
-        graph.add_edge(3, 4).unwrap();
-        graph.add_edge(4, 0).unwrap();
+        // Unit weights reproduce the unweighted index
+        assert_eq!(weighted, unweighted);
+        assert_eq!(weighted.weighted_zagreb_index(), weighted.first_zagreb_index() as f64);
+        assert_eq!(unweighted.weighted_zagreb_index(), unweighted.first_zagreb_index() as f64);
+
+        // Re-adding an edge replaces its weight
+        weighted.add_weighted_edge(1, 0, 3.0).unwrap();
+        assert_eq!(weighted.edge_count(), 6);
+        assert_eq!(weighted.edge_weight(0, 1), Some(3.0));
+        assert_eq!(weighted.edge_weight(0, 3), None);
+        assert_eq!(weighted.weighted_degree(0).unwrap(), 5.0);
+        assert_eq!(weighted.weighted_degree(1).unwrap(), 4.0);
+        // Degrees are now 5, 4, 3, 2, 2
+        assert_eq!(weighted.weighted_zagreb_index(), 25.0 + 16.0 + 9.0 + 4.0 + 4.0);
+        assert_ne!(weighted, unweighted);
+
+        assert!(weighted.add_weighted_edge(0, 3, f64::NAN).is_err());
+        assert!(weighted.add_weighted_edge(0, 9, 2.0).is_err());
+        assert!(weighted.weighted_degree(9).is_err());
+    }
 
-        // Add spoke edges (connecting outer and inner vertices)
-        graph.add_edge(0, 5).unwrap();
-        graph.add_edge(1, 6).unwrap();
-        graph.add_edge(2, 7).unwrap();
-        graph.add_edge(3, 8).unwrap();
-        graph.add_edge(4, 9).unwrap();
-
-        // Add inner pentagram edges
-        graph.add_edge(5, 7).unwrap();
-        graph.add_edge(7, 9).unwrap();
-        graph.add_edge(9, 6).unwrap();
-        graph.add_edge(6, 8).unwrap();
-        graph.add_edge(8, 5).unwrap();
+    #[test]
+    fn test_empty_and_single_vertex_graphs() {
+        for n in 0..2 {
+            let graph = Graph::new(n);
+
+            for k in 0..3 {
+                assert!(!graph.is_k_connected(k + 1));
+                assert!(!graph.is_k_connected_with(k + 1, true));
+                assert!(!graph.is_k_connected_approx(k + 1));
+                assert!(!graph.is_k_connected_exact(k + 1));
+            }
+            assert!(!graph.is_likely_hamiltonian_with(false));
+            assert!(!graph.is_likely_hamiltonian_with(true));
+            assert!(!graph.is_likely_traceable_with(false));
+            assert!(!graph.is_likely_traceable_with(true));
+            assert!(!graph.is_path());
+            assert!(!graph.is_star());
+            assert!(!graph.is_cycle());
+
+            assert_eq!(graph.first_zagreb_index(), 0);
+            assert_eq!(graph.edge_connectivity(), 0);
+            assert_eq!(graph.independence_number_approx(), n);
+            assert!(graph.zagreb_upper_bound().is_finite());
+            let _ = graph.paper_theorem_report();
+            let _ = graph.report();
+        }
 
-        // Verify basic properties
-        assert_eq!(graph.vertex_count(), 10);
-        assert_eq!(graph.edge_count(), 15);
-        assert_eq!(graph.min_degree(), 3); // 3-regular graph
-        assert_eq!(graph.max_degree(), 3); // 3-regular graph
+        assert!(!Graph::new(0).is_k_connected(1));
+    }
 
-        // Calculate Zagreb index: 10 vertices with degree 3, so 10 * 3^2 = 90
-        assert_eq!(graph.first_zagreb_index(), 90);
+    #[test]
+    fn test_bfs_and_dfs() {
+        let path = Graph::path(6);
 
-        // Petersen graph is 3-connected
-        assert!(graph.is_k_connected(3, false));
+        // BFS from an interior vertex visits vertices in non-decreasing distance
+        let order: Vec<usize> = path.bfs(2).collect();
+        assert_eq!(order, vec![2, 1, 3, 0, 4, 5]);
+        let distances: Vec<usize> = order.iter().map(|&v| v.abs_diff(2)).collect();
+        assert!(distances.windows(2).all(|pair| pair[0] <= pair[1]));
 
-        // Petersen graph is NOT Hamiltonian (famous result in graph theory)
-        assert!(!graph.is_likely_hamiltonian(false));
+        // DFS follows one branch to the end before backtracking
+        assert_eq!(path.dfs(2).collect::<Vec<_>>(), vec![2, 1, 0, 3, 4, 5]);
 
-        // Petersen graph IS traceable (it has a Hamiltonian path)
-        assert!(graph.is_likely_traceable(false));
+        let mut tree = Graph::new(7);
+        for i in 1..7 {
+            tree.add_edge(i, (i - 1) / 2).unwrap();
+        }
+        assert_eq!(tree.bfs(0).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(tree.dfs(0).collect::<Vec<_>>(), vec![0, 1, 3, 4, 2, 5, 6]);
 
-        // Test independent set properties
-        // Petersen graph's independence number is 4
-        let independence_num = graph.independence_number_approx();
-        assert!(
-            independence_num >= 4,
-            "Expected independence number >= 4, got {}",
-            independence_num
-        );
+        // Only the component of the start vertex is visited
+        let disconnected = Graph::from_edges(4, [(0, 1), (2, 3)]).unwrap();
+        assert_eq!(disconnected.bfs(3).collect::<Vec<_>>(), vec![3, 2]);
+        assert_eq!(disconnected.dfs(0).collect::<Vec<_>>(), vec![0, 1]);
     }
 
     #[test]
-    fn test_zagreb_index_calculation() {
-        // Complete graph K5 - each vertex has degree 4, so sum of squares is 5 * 4^2 = 80
-        let mut complete5 = Graph::new(5);
-        for i in 0..4 {
+    #[should_panic(expected = "Start vertex 3 out of bounds")]
+    fn test_bfs_out_of_bounds() {
+        let _ = Graph::new(3).bfs(3);
+    }
+
+    #[test]
+    fn test_shortest_path_and_distance() {
+        let path = Graph::path(5);
+        assert_eq!(path.shortest_path(0, 4), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(path.shortest_path(3, 1), Some(vec![3, 2, 1]));
+        assert_eq!(path.distance(0, 4), Some(4));
+        assert_eq!(path.shortest_path(2, 2), Some(vec![2]));
+        assert_eq!(path.distance(2, 2), Some(0));
+
+        // On a cycle the path goes the short way round
+        let cycle = Graph::cycle(8);
+        assert_eq!(cycle.shortest_path(0, 6), Some(vec![0, 7, 6]));
+        assert_eq!(cycle.distance(1, 5), Some(4));
+        assert_eq!(cycle.shortest_path(1, 5).unwrap().len(), 5);
+
+        let disconnected = Graph::from_edges(4, [(0, 1), (2, 3)]).unwrap();
+        assert_eq!(disconnected.shortest_path(0, 3), None);
+        assert_eq!(disconnected.distance(0, 3), None);
+
+        // Out-of-bounds vertices
+        assert_eq!(path.shortest_path(0, 5), None);
+        assert_eq!(path.distance(7, 0), None);
+    }
+
+    #[test]
+    fn test_clear_edges_and_clear() {
+        let mut graph = Graph::new(5);
+        for i in 0..5 {
             for j in (i + 1)..5 {
-                complete5.add_edge(i, j).unwrap();
+                graph.add_weighted_edge(i, j, 2.0).unwrap();
             }
         }
-        assert_eq!(complete5.first_zagreb_index(), 80);
 
-        // Path graph P5 - two vertices of degree 1, three vertices of degree 2, so 2*1^2 + 3*2^2 = 14
-        let mut path5 = Graph::new(5);
-        path5.add_edge(0, 1).unwrap();
-        path5.add_edge(1, 2).unwrap();
-        path5.add_edge(2, 3).unwrap();
-        path5.add_edge(3, 4).unwrap();
-        assert_eq!(path5.first_zagreb_index(), 14);
+        graph.clear_edges();
+        assert_eq!(graph.edge_count(), 0);
+        assert_eq!(graph.first_zagreb_index(), 0);
+        assert_eq!(graph.vertex_count(), 5);
+        assert_eq!(graph, Graph::new(5));
 
-        // Empty graph
-        let empty = Graph::new(5);
-        assert_eq!(empty.first_zagreb_index(), 0);
+        // The graph can be refilled, and old weights do not come back
+        graph.add_edge(0, 1).unwrap();
+        assert_eq!(graph.edge_weight(0, 1), Some(1.0));
+        assert_eq!(graph.first_zagreb_index(), 2);
 
-        // Single vertex graph
-        let single = Graph::new(1);
-        assert_eq!(single.first_zagreb_index(), 0);
+        graph.clear();
+        assert_eq!(graph, Graph::new(5));
+        assert_eq!(graph.max_degree(), 0);
+    }
+
+    #[test]
+    fn test_remove_vertex() {
+        // Removing the center of a star leaves isolated vertices
+        let mut star = Graph::star(6);
+        star.remove_vertex(0).unwrap();
+        assert_eq!(star, Graph::new(5));
+
+        // Vertices above the removed one shift down by one
+        let mut path = Graph::new(5);
+        for i in 0..4 {
+            path.add_weighted_edge(i, i + 1, (i + 1) as f64).unwrap();
+        }
+        path.remove_vertex(2).unwrap();
+        assert_eq!(path.vertex_count(), 4);
+        assert_eq!(path.edge_list(), vec![(0, 1), (2, 3)]);
+        assert_eq!(path.edge_weight(0, 1), Some(1.0));
+        assert_eq!(path.edge_weight(2, 3), Some(4.0));
+        assert_eq!(path.first_zagreb_index(), 4);
+
+        assert_eq!(path.remove_vertex(4), Err(GraphError::VertexOutOfBounds(4)));
+
+        let mut single = Graph::new(1);
+        single.remove_vertex(0).unwrap();
+        assert_eq!(single, Graph::new(0));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_degree_scans_match_serial() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut graphs = vec![Graph::new(0), Graph::new(5)];
+        for &p in &[0.05, 0.5, 0.95] {
+            graphs.push(Graph::erdos_renyi(300, p, &mut rng));
+        }
+
+        for graph in &graphs {
+            assert_eq!(graph.min_degree_parallel(), graph.min_degree_serial());
+            assert_eq!(graph.max_degree_parallel(), graph.max_degree_serial());
+        }
     }
 
     #[test]
-    fn test_hamiltonian_detection() {
-        // Known Hamiltonian graphs
-        let mut complete5 = Graph::new(5);
-        for i in 0..4 {
-            for j in (i + 1)..5 {
-                complete5.add_edge(i, j).unwrap();
+    fn test_bitset_adjacency_matches_hash_sets() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(21);
+        for &(n, p) in &[(10, 0.4), (70, 0.2), (130, 0.5)] {
+            let graph = Graph::erdos_renyi(n, p, &mut rng);
+            let bitset = BitsetAdjacency::from_graph(&graph);
+
+            // Both workspaces find shortest paths of the same length
+            for t in 0..n {
+                let from_bitset = bitset.find_path(0, t).map(|path| path.len());
+                let from_sets = graph.find_path(0, t).map(|path| path.len());
+                assert_eq!(from_bitset, from_sets);
+            }
+
+            for &(s, t) in &[(0, n - 1), (1, n / 2), (2, 3)] {
+                let mut bitset = BitsetAdjacency::from_graph(&graph);
+                let mut sets = HashSetAdjacency {
+                    graph: &graph,
+                    edges: graph.edges.clone(),
+                };
+                let direct = usize::from(graph.edges[s].contains(&t));
+                if direct == 1 {
+                    bitset.remove_edge(s, t);
+                    sets.remove_edge(s, t);
+                }
+
+                // Ties between shortest paths may be broken differently, but neither greedy
+                // count can exceed the max-flow value
+                let exact = graph.local_vertex_connectivity(s, t);
+                assert!(direct + count_greedy_disjoint_paths(&mut bitset, s, t, n) <= exact);
+                assert!(direct + count_greedy_disjoint_paths(&mut sets, s, t, n) <= exact);
             }
         }
-        assert!(complete5.is_likely_hamiltonian(true));
 
-        let mut cycle5 = Graph::new(5);
-        cycle5.add_edge(0, 1).unwrap();
-        cycle5.add_edge(1, 2).unwrap();
-        cycle5.add_edge(2, 3).unwrap();
-        cycle5.add_edge(3, 4).unwrap();
-        cycle5.add_edge(4, 0).unwrap();
-        assert!(cycle5.is_likely_hamiltonian(true));
+        // Isolating a vertex clears it from both sides of the bitset
+        let star = Graph::star(70);
+        let mut bitset = BitsetAdjacency::from_graph(&star);
+        assert!(bitset.find_path(1, 69).is_some());
+        bitset.isolate(0);
+        assert!(bitset.rows.iter().all(|&word| word == 0));
+        assert!(bitset.find_path(1, 69).is_none());
+    }
 
-        // Known non-Hamiltonian graphs
-        let mut star5 = Graph::new(5);
-        star5.add_edge(0, 1).unwrap();
-        star5.add_edge(0, 2).unwrap();
-        star5.add_edge(0, 3).unwrap();
-        star5.add_edge(0, 4).unwrap();
-        assert!(!star5.is_likely_hamiltonian(true));
+    #[test]
+    fn test_k_connectivity_approx_barbell() {
+        // Two copies of K5 joined by the single edge 4-5
+        let mut barbell = Graph::new(10);
+        for offset in [0, 5] {
+            for i in 0..5 {
+                for j in (i + 1)..5 {
+                    barbell.add_edge(offset + i, offset + j).unwrap();
+                }
+            }
+        }
+        barbell.add_edge(4, 5).unwrap();
 
-        // Create Petersen graph (known to be non-Hamiltonian)
-        let mut petersen = Graph::new(10);
-        // Add outer cycle
-        petersen.add_edge(0, 1).unwrap();
-        petersen.add_edge(1, 2).unwrap();
-        petersen.add_edge(2, 3).unwrap();
-        petersen.add_edge(3, 4).unwrap();
-        petersen.add_edge(4, 0).unwrap();
-        // Add spokes
-        petersen.add_edge(0, 5).unwrap();
-        petersen.add_edge(1, 6).unwrap();
-        petersen.add_edge(2, 7).unwrap();
-        petersen.add_edge(3, 8).unwrap();
-        petersen.add_edge(4, 9).unwrap();
-        // Add inner pentagram
-        petersen.add_edge(5, 7).unwrap();
-        petersen.add_edge(7, 9).unwrap();
-        petersen.add_edge(9, 6).unwrap();
-        petersen.add_edge(6, 8).unwrap();
-        petersen.add_edge(8, 5).unwrap();
-        assert!(!petersen.is_likely_hamiltonian(true));
+        // 21 edges clear the old (n - 1)k/2 + 1 density threshold for k = 2, yet removing
+        // vertex 4 (or 5) disconnects the graph
+        assert_eq!(barbell.edge_count(), 21);
+        for k in 1..5 {
+            assert_eq!(
+                barbell.is_k_connected_approx(k),
+                barbell.is_k_connected_exact(k),
+                "approx and exact disagree for k={}",
+                k
+            );
+        }
+        assert!(barbell.is_k_connected_approx(1));
+        assert!(!barbell.is_k_connected_approx(2));
+        assert!(!barbell.is_k_connected(2));
     }
 
     #[test]
-    fn test_traceable_detection() {
-        // Test path graph (traceable by definition)
-        let mut path = Graph::new(5);
-        path.add_edge(0, 1).unwrap();
-        path.add_edge(1, 2).unwrap();
-        path.add_edge(2, 3).unwrap();
-        path.add_edge(3, 4).unwrap();
-        assert!(path.is_likely_traceable(true));
+    fn test_vertex_connectivity() {
+        let petersen = Graph::petersen();
+        assert_eq!(petersen.vertex_connectivity(), 3);
 
-        // Test star graph (traceable)
-        let mut star = Graph::new(5);
-        star.add_edge(0, 1).unwrap();
-        star.add_edge(0, 2).unwrap();
-        star.add_edge(0, 3).unwrap();
-        star.add_edge(0, 4).unwrap();
-        assert!(star.is_likely_traceable(true));
+        let cycle = Graph::cycle(7);
+        assert_eq!(cycle.vertex_connectivity(), 2);
 
-        // Test Petersen graph (known to be traceable)
-        let mut petersen = Graph::new(10);
-        // Add outer cycle
-        petersen.add_edge(0, 1).unwrap();
-        petersen.add_edge(1, 2).unwrap();
-        petersen.add_edge(2, 3).unwrap();
-        petersen.add_edge(3, 4).unwrap();
-        petersen.add_edge(4, 0).unwrap();
-        // Add spokes
-        petersen.add_edge(0, 5).unwrap();
-        petersen.add_edge(1, 6).unwrap();
-        petersen.add_edge(2, 7).unwrap();
-        petersen.add_edge(3, 8).unwrap();
-        petersen.add_edge(4, 9).unwrap();
-        // Add inner pentagram
-        petersen.add_edge(5, 7).unwrap();
-        petersen.add_edge(7, 9).unwrap();
-        petersen.add_edge(9, 6).unwrap();
-        petersen.add_edge(6, 8).unwrap();
-        petersen.add_edge(8, 5).unwrap();
-        assert!(petersen.is_likely_traceable(true));
+        let path = Graph::path(6);
+        assert_eq!(path.vertex_connectivity(), 1);
+
+        let complete = Graph::complete(5);
+        assert_eq!(complete.vertex_connectivity(), 4);
+
+        // Two triangles sharing a vertex, and a 4x4 grid
+        let bowtie =
+            Graph::from_edges(5, [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]).unwrap();
+        assert_eq!(bowtie.vertex_connectivity(), 1);
+        assert_eq!(Graph::grid(4, 4).vertex_connectivity(), 2);
+
+        assert_eq!(Graph::new(4).vertex_connectivity(), 0);
+        assert_eq!(Graph::new(1).vertex_connectivity(), 0);
+        assert_eq!(Graph::new(0).vertex_connectivity(), 0);
     }
 
     #[test]
-    fn test_zagreb_upper_bound() {
-        // Create various graph types
-        let mut cycle = Graph::new(5);
-        cycle.add_edge(0, 1).unwrap();
-        cycle.add_edge(1, 2).unwrap();
-        cycle.add_edge(2, 3).unwrap();
-        cycle.add_edge(3, 4).unwrap();
-        cycle.add_edge(4, 0).unwrap();
-
-        let mut complete = Graph::new(5);
-        for i in 0..4 {
-            for j in (i + 1)..5 {
-                complete.add_edge(i, j).unwrap();
+    fn test_bipartition() {
+        let c6 = Graph::cycle(6);
+        assert!(c6.is_bipartite());
+        assert_eq!(c6.bipartition(), Some((vec![0, 2, 4], vec![1, 3, 5])));
+
+        let c5 = Graph::cycle(5);
+        assert!(!c5.is_bipartite());
+        assert_eq!(c5.bipartition(), None);
+
+        // K_{2,3} with sides {0, 1} and {2, 3, 4}
+        let mut k23 = Graph::new(5);
+        for u in 0..2 {
+            for v in 2..5 {
+                k23.add_edge(u, v).unwrap();
             }
         }
+        assert_eq!(k23.bipartition(), Some((vec![0, 1], vec![2, 3, 4])));
 
-        let mut star = Graph::new(5);
-        star.add_edge(0, 1).unwrap();
-        star.add_edge(0, 2).unwrap();
-        star.add_edge(0, 3).unwrap();
-        star.add_edge(0, 4).unwrap();
-
-        // Verify the Zagreb index is always less than or equal to the upper bound
-        assert!(cycle.first_zagreb_index() as f64 <= cycle.zagreb_upper_bound());
-        assert!(complete.first_zagreb_index() as f64 <= complete.zagreb_upper_bound());
-        assert!(star.first_zagreb_index() as f64 <= star.zagreb_upper_bound());
+        // Every component is colored, and isolated vertices land on the first side
+        let forest = Graph::from_edges(5, [(1, 2), (3, 4)]).unwrap();
+        assert_eq!(forest.bipartition(), Some((vec![0, 1, 3], vec![2, 4])));
+        assert!(Graph::new(0).is_bipartite());
     }
 
     #[test]
-    fn test_graph_type_detection() {
-        // Test complete graph detection
-        let mut complete = Graph::new(5);
-        for i in 0..4 {
-            for j in (i + 1)..5 {
-                complete.add_edge(i, j).unwrap();
-            }
+    fn test_triangle_count_and_clustering() {
+        let k4 = Graph::complete(4);
+        assert_eq!(k4.triangle_count(), 4);
+        assert_eq!(k4.clustering_coefficient(), 1.0);
+
+        let mut tree = Graph::new(7);
+        for i in 1..7 {
+            tree.add_edge(i, (i - 1) / 2).unwrap();
         }
-        assert!(complete.is_complete());
-
-        // Test cycle graph detection
-        let mut cycle = Graph::new(5);
-        cycle.add_edge(0, 1).unwrap();
-        cycle.add_edge(1, 2).unwrap();
-        cycle.add_edge(2, 3).unwrap();
-        cycle.add_edge(3, 4).unwrap();
-        cycle.add_edge(4, 0).unwrap();
-        assert!(cycle.is_cycle());
+        assert_eq!(tree.triangle_count(), 0);
+        assert_eq!(tree.clustering_coefficient(), 0.0);
 
-        // Test star graph detection
-        let mut star = Graph::new(5);
-        star.add_edge(0, 1).unwrap();
-        star.add_edge(0, 2).unwrap();
-        star.add_edge(0, 3).unwrap();
-        star.add_edge(0, 4).unwrap();
-        assert!(star.is_star());
+        // A triangle with a pendant vertex: 1 triangle and 5 connected triples
+        let paw = Graph::from_edges(4, [(0, 1), (1, 2), (2, 0), (2, 3)]).unwrap();
+        assert_eq!(paw.triangle_count(), 1);
+        assert!((paw.clustering_coefficient() - 0.6).abs() < 1e-12);
 
-        // Test path graph detection
-        let mut path = Graph::new(5);
-        path.add_edge(0, 1).unwrap();
-        path.add_edge(1, 2).unwrap();
-        path.add_edge(2, 3).unwrap();
-        path.add_edge(3, 4).unwrap();
-        assert!(path.is_path());
+        assert_eq!(Graph::new(0).clustering_coefficient(), 0.0);
+    }
 
-        // Test non-matches
-        assert!(!cycle.is_complete());
-        assert!(!star.is_cycle());
-        assert!(!path.is_star());
-        assert!(!complete.is_path());
+    #[test]
+    fn test_second_zagreb_index() {
+        // Every edge of the Petersen graph joins two degree-3 vertices
+        let petersen = Graph::petersen();
+        assert_eq!(petersen.second_zagreb_index(), 15 * 9);
+
+        // P4 has degrees 1, 2, 2, 1: 1·2 + 2·2 + 2·1
+        let p4 = Graph::path(4);
+        assert_eq!(p4.second_zagreb_index(), 8);
+
+        let star = Graph::star(5);
+        assert_eq!(star.second_zagreb_index(), 16);
+        assert_eq!(Graph::new(3).second_zagreb_index(), 0);
     }
 
     #[test]
-    fn test_theorem_implementations() {
-        // Test Theorem 1 with k=2
-        let mut graph = Graph::new(10);
-        // Create a k-connected graph (k=2) that meets the Zagreb index criteria
-        // and verify it's correctly identified as Hamiltonian
-        // This would need to be constructed based on the theorem's specifics
+    fn test_vertex_disjoint_paths() {
+        let complete = Graph::complete(5);
+        assert_eq!(complete.vertex_disjoint_paths(0, 1), Ok(4));
+
+        let cycle = Graph::cycle(5);
+        assert_eq!(cycle.vertex_disjoint_paths(0, 2), Ok(2));
+        assert_eq!(cycle.vertex_disjoint_paths(0, 1), Ok(2));
+
+        let path = Graph::path(5);
+        assert_eq!(path.vertex_disjoint_paths(0, 4), Ok(1));
+
+        // Two triangles joined by a perfect matching (the triangular prism)
+        let prism = Graph::from_edges(
+            6,
+            [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (0, 3), (1, 4), (2, 5)],
+        )
+        .unwrap();
+        assert_eq!(prism.vertex_disjoint_paths(0, 5), Ok(3));
+
+        assert_eq!(Graph::new(3).vertex_disjoint_paths(0, 2), Ok(0));
+        assert_eq!(path.vertex_disjoint_paths(0, 5), Err(GraphError::VertexOutOfBounds(5)));
+        assert_eq!(path.vertex_disjoint_paths(2, 2), Err(GraphError::DuplicateVertex(2)));
+    }
 
-        // Test Theorem 2 with k=1
-        // Similarly construct and test
+    #[test]
+    fn test_vertex_disjoint_paths_beyond_attempt_limit() {
+        // s = 0 and t = 1 joined through 150 private middle vertices, plus a chord between
+        // two middle vertices so the graph is none of the special-cased families
+        let middle = 150;
+        let mut graph = Graph::new(middle + 2);
+        for m in 2..middle + 2 {
+            graph.add_edge(0, m).unwrap();
+            graph.add_edge(m, 1).unwrap();
+        }
+        graph.add_edge(2, 3).unwrap();
 
-        // Test Theorem 3 upper bounds
-        // Create a graph and verify the bounds match expected values
+        // Far more than 100 augmenting paths are needed to reach the exact count
+        assert_eq!(graph.find_vertex_disjoint_paths(0, 1), middle);
+        assert_eq!(graph.vertex_disjoint_paths(0, 1), Ok(middle));
+        assert_eq!(graph.vertex_disjoint_paths(2, 3), Ok(3));
     }
 
     #[test]
-    fn test_independence_number() {
-        // Test on a path graph P5 (should be 3)
-        let mut path = Graph::new(5);
-        path.add_edge(0, 1).unwrap();
-        path.add_edge(1, 2).unwrap();
-        path.add_edge(2, 3).unwrap();
-        path.add_edge(3, 4).unwrap();
-        assert_eq!(path.independence_number_approx(), 3);
-
-        // Test on a cycle graph C5 (should be 2)
-        let mut cycle = Graph::new(5);
-        cycle.add_edge(0, 1).unwrap();
-        cycle.add_edge(1, 2).unwrap();
-        cycle.add_edge(2, 3).unwrap();
-        cycle.add_edge(3, 4).unwrap();
-        cycle.add_edge(4, 0).unwrap();
-        assert_eq!(cycle.independence_number_approx(), 2);
+    fn test_centrality_star() {
+        let star = Graph::star(5);
+
+        let degree = star.degree_centrality();
+        assert_eq!(degree[0], 1.0);
+        assert!(degree[1..].iter().all(|&c| c == 0.25));
+
+        // The center is one step from everything; a leaf averages (1 + 2 + 2 + 2) / 4
+        let closeness = star.closeness_centrality();
+        assert_eq!(closeness[0], 1.0);
+        assert!(closeness[1..].iter().all(|&c| (c - 4.0 / 7.0).abs() < 1e-12));
+
+        // Disconnected: the path 0-1-2 only averages over itself, and 3 is isolated
+        let split = Graph::from_edges(4, [(0, 1), (1, 2)]).unwrap();
+        let closeness = split.closeness_centrality();
+        assert_eq!(closeness[1], 1.0);
+        assert!((closeness[0] - 2.0 / 3.0).abs() < 1e-12);
+        assert_eq!(closeness[3], 0.0);
+        assert_eq!(split.degree_centrality()[3], 0.0);
+    }
 
-        // Test on a complete graph K5 (should be 1)
-        let mut complete = Graph::new(5);
-        for i in 0..4 {
-            for j in (i + 1)..5 {
-                complete.add_edge(i, j).unwrap();
-            }
-        }
-        assert_eq!(complete.independence_number_approx(), 1);
+    #[test]
+    fn test_line_graph() {
+        let triangle = Graph::cycle(3);
+
+        let (line, mapping) = triangle.line_graph();
+        assert_eq!(mapping, vec![(0, 1), (0, 2), (1, 2)]);
+        assert_eq!(line.vertex_count(), 3);
+        assert!(line.is_complete());
+
+        // L(P4) is P3, with the middle edge in the middle
+        let path = Graph::path(4);
+
+        let (line, mapping) = path.line_graph();
+        assert_eq!(mapping, vec![(0, 1), (1, 2), (2, 3)]);
+        assert_eq!(line.vertex_count(), 3);
+        assert_eq!(line.edge_count(), 2);
+        assert_eq!(line.degree(1), Ok(2));
+        assert!(line.is_path());
     }
 
     #[test]
-    fn test_theorem_1_implementation() {
-        // Theorem 1 deals with Hamiltonian properties for k-connected graphs (k ≥ 2)
+    fn test_dirac_and_ore_conditions() {
+        let c6 = Graph::cycle(6);
+        assert!(!c6.satisfies_dirac());
+        assert!(!c6.satisfies_ore());
 
-        // First, check if the implementation correctly identifies known Hamiltonian graphs
-        let mut complete5 = Graph::new(5);
+        let k5 = Graph::complete(5);
+        assert!(k5.satisfies_dirac());
+        assert!(k5.satisfies_ore());
+
+        // K4 plus a degree-2 vertex: δ = 2 < 5/2, but every non-adjacent pair sums to 5
+        let mut graph = Graph::new(5);
         for i in 0..4 {
-            for j in (i+1)..5 {
-                complete5.add_edge(i, j).unwrap();
+            for j in (i + 1)..4 {
+                graph.add_edge(i, j).unwrap();
             }
         }
-        assert!(complete5.is_likely_hamiltonian(false),
-                "Complete graph K5 should be identified as Hamiltonian");
-
-        let mut cycle6 = Graph::new(6);
-        for i in 0..6 {
-            cycle6.add_edge(i, (i+1) % 6).unwrap();
-        }
-        assert!(cycle6.is_likely_hamiltonian(false),
-                "Cycle graph C6 should be identified as Hamiltonian");
-
-        // Now create a graph that satisfies the conditions from the paper
-        // We'll create a k-connected graph for k=2
-        let mut graph1 = Graph::new(8);
-        // Create a cycle as base structure (ensures 2-connectivity)
-        for i in 0..8 {
-            graph1.add_edge(i, (i+1) % 8).unwrap();
+        graph.add_edge(4, 0).unwrap();
+        graph.add_edge(4, 1).unwrap();
+        assert!(!graph.satisfies_dirac());
+        assert!(graph.satisfies_ore());
+        assert!(graph.hamiltonian_cycle().is_some());
+
+        // K_{2,3} has δ = 2 = ⌊5/2⌋ but 2δ < n, so Dirac must not report it Hamiltonian
+        let mut k23 = Graph::new(5);
+        for u in 0..2 {
+            for v in 2..5 {
+                k23.add_edge(u, v).unwrap();
+            }
         }
-        // Add diagonals to increase Zagreb index
-        graph1.add_edge(0, 2).unwrap();
-        graph1.add_edge(0, 3).unwrap();
-        graph1.add_edge(0, 4).unwrap();
-        graph1.add_edge(1, 3).unwrap();
-        graph1.add_edge(1, 4).unwrap();
-        graph1.add_edge(1, 5).unwrap();
-        graph1.add_edge(2, 4).unwrap();
-        graph1.add_edge(2, 5).unwrap();
-        graph1.add_edge(2, 6).unwrap();
-        graph1.add_edge(3, 5).unwrap();
-        graph1.add_edge(3, 6).unwrap();
-        graph1.add_edge(3, 7).unwrap();
-        graph1.add_edge(4, 6).unwrap();
-        graph1.add_edge(4, 7).unwrap();
-        graph1.add_edge(5, 7).unwrap();
-
-        let k = 2;
-        let n = graph1.vertex_count();
-        let e = graph1.edge_count();
-        let delta = graph1.min_degree();
-        let delta_max = graph1.max_degree();
-        let z1 = graph1.first_zagreb_index();
-
-        // Calculate Theorem 1 threshold
-        let part1 = (n - k - 1) * delta_max * delta_max;
-        let part2 = (e * e) / (k + 1);
-        let part3 = ((n - k - 1) as f64).sqrt() - (delta as f64).sqrt();
-        let part3_squared = part3 * part3;
-        let threshold = part1 + part2 + (part3_squared * e as f64) as usize;
-
-        println!("Theorem 1 test: n={}, k={}, e={}, delta={}, delta_max={}",
-                 n, k, e, delta, delta_max);
-        println!("Theorem 1 test: Zagreb index = {}, threshold = {}", z1, threshold);
+        assert!(!k23.satisfies_dirac());
+        assert!(!k23.is_likely_hamiltonian_with(true));
+        assert!(!k23.is_likely_hamiltonian_with(false));
+        assert_eq!(k23.hamiltonian_cycle(), None);
+
+        // Too small to have a Hamiltonian cycle
+        let k2 = Graph::complete(2);
+        assert!(!k2.satisfies_dirac());
+        assert!(!k2.satisfies_ore());
+    }
 
-        // It's okay if the graph doesn't meet the threshold as long as it's Hamiltonian
-        // The paper provides a sufficient (but not necessary) condition
-        let hamiltonian_by_property = graph1.is_likely_hamiltonian(false);
-        println!("Is Hamiltonian according to implementation: {}", hamiltonian_by_property);
+    #[test]
+    fn test_eulerian() {
+        let c5 = Graph::cycle(5);
+        assert!(c5.is_eulerian());
+        assert!(c5.has_eulerian_path());
+
+        let circuit = c5.eulerian_circuit().unwrap();
+        assert_eq!(circuit.len(), 6);
+        assert_eq!(circuit.first(), Some(&0));
+        assert_eq!(circuit.last(), Some(&0));
+
+        // Every edge is traversed exactly once
+        let mut traversed: Vec<(usize, usize)> = circuit
+            .windows(2)
+            .map(|w| (w[0].min(w[1]), w[0].max(w[1])))
+            .collect();
+        traversed.sort_unstable();
+        assert_eq!(traversed, c5.edge_list());
+
+        // A path has two odd endpoints: no circuit, but a path from one end to the other
+        let path = Graph::path(4);
+        assert!(!path.is_eulerian());
+        assert!(path.eulerian_circuit().is_none());
+        assert!(path.has_eulerian_path());
+        assert_eq!(path.eulerian_path(), Some(vec![0, 1, 2, 3]));
+
+        // Two triangles sharing vertex 0 need a detour through the second triangle
+        let bowtie =
+            Graph::from_edges(5, [(0, 1), (1, 2), (2, 0), (0, 3), (3, 4), (4, 0)]).unwrap();
+        let circuit = bowtie.eulerian_circuit().unwrap();
+        assert_eq!(circuit.len(), 7);
+
+        // A star with three leaves has four odd vertices
+        let star = Graph::star(4);
+        assert!(!star.has_eulerian_path());
+        assert!(star.eulerian_path().is_none());
+    }
 
-        // For this test, we'll check if the implementation agrees with known Hamiltonian properties
-        assert!(hamiltonian_by_property,
-                "The graph should be identified as Hamiltonian");
+    #[test]
+    fn test_spanning_tree() {
+        let c6 = Graph::cycle(6);
 
-        // Test the special case mentioned in the paper: K_{k,k+1}
-        // For k=2, we shouldn't hard-code whether it's Hamiltonian or not,
-        // because the implementation might handle this case specially
-        // Instead, let's just print whether the implementation thinks it's Hamiltonian
-        let mut bipartite = Graph::new(5);
-        // Connect vertices 0,1 to vertices 2,3,4
-        bipartite.add_edge(0, 2).unwrap();
-        bipartite.add_edge(0, 3).unwrap();
-        bipartite.add_edge(0, 4).unwrap();
-        bipartite.add_edge(1, 2).unwrap();
-        bipartite.add_edge(1, 3).unwrap();
-        bipartite.add_edge(1, 4).unwrap();
-
-        let bipartite_hamiltonian = bipartite.is_likely_hamiltonian(false);
-        println!("K_{{2,3}} bipartite graph is Hamiltonian according to implementation: {}",
-                 bipartite_hamiltonian);
+        let tree_edges = c6.spanning_tree().unwrap();
+        assert_eq!(tree_edges.len(), 5);
 
-        // Based on the paper, K_{k,k+1} is NOT Hamiltonian for k≥2
-        // However, we'll check if the implementation is consistent with itself
+        // Five edges on six vertices that connect everything form a tree, so no cycle
+        let mut tree = Graph::new(6);
+        for &(u, v) in &tree_edges {
+            assert!(c6.edge_list().contains(&(u.min(v), u.max(v))));
+            tree.add_edge(u, v).unwrap();
+        }
+        assert!(tree.is_connected());
+        assert_eq!(tree.girth(), None);
 
-        // Check if the implementation handles K_{k,k+1} as a special case
-        let special_case_handled = bipartite.is_k_connected(k, false) &&
-            !bipartite_hamiltonian;
+        // Two components and an isolated vertex
+        let split = Graph::from_edges(6, [(0, 1), (1, 2), (2, 0), (3, 4)]).unwrap();
+        assert!(split.spanning_tree().is_none());
+        assert_eq!(
+            split.spanning_forest(),
+            vec![vec![(0, 1), (0, 2)], vec![(3, 4)], vec![]]
+        );
+    }
 
-        println!("K_{{2,3}} is k-connected: {}", bipartite.is_k_connected(k, false));
-        println!("Special case K_{{k,k+1}} handled: {}", special_case_handled);
+    #[test]
+    fn test_tree_and_forest() {
+        let star = Graph::star(5);
+        assert!(star.is_tree());
+        assert!(star.is_forest());
+
+        let path = Graph::path(4);
+        assert!(path.is_tree());
+        assert!(path.is_forest());
+
+        let two_edges = Graph::from_edges(4, [(0, 1), (2, 3)]).unwrap();
+        assert!(!two_edges.is_tree());
+        assert!(two_edges.is_forest());
+
+        // A triangle plus an isolated vertex has n - 1 edges but is neither
+        let triangle = Graph::from_edges(4, [(0, 1), (1, 2), (2, 0)]).unwrap();
+        assert!(!triangle.is_tree());
+        assert!(!triangle.is_forest());
+
+        assert!(Graph::new(1).is_tree());
+        assert!(!Graph::new(0).is_tree());
+    }
 
-        // If the implementation doesn't specially handle K_{k,k+1}, then we don't enforce that it's non-Hamiltonian
-        // Otherwise, we'll check that it correctly identifies it as non-Hamiltonian
-        if special_case_handled {
-            assert!(!bipartite_hamiltonian,
-                    "K_{{2,3}} bipartite graph should be identified as non-Hamiltonian if special cases are handled");
+    #[test]
+    fn test_classifiers_reject_disjoint_unions() {
+        // Two disjoint triangles are 2-regular with n edges, but not a cycle
+        let mut triangles = Graph::new(6);
+        for base in [0, 3] {
+            for i in 0..3 {
+                triangles.add_edge(base + i, base + (i + 1) % 3).unwrap();
+            }
         }
+        assert!(!triangles.is_cycle());
+        assert!(!triangles.is_k_connected(2));
+
+        // An edge plus a disjoint triangle has n - 1 edges and two leaves, but is not a path
+        let edge_and_triangle = Graph::from_edges(5, [(0, 1), (2, 3), (3, 4), (4, 2)]).unwrap();
+        assert!(!edge_and_triangle.is_path());
     }
 
     #[test]
-    fn test_theorem_2_implementation() {
-        // Theorem 2 deals with traceable properties for k-connected graphs (k ≥ 1)
-
-        // First, check if the implementation correctly identifies known traceable graphs
-        let mut path5 = Graph::new(5);
-        for i in 0..4 {
-            path5.add_edge(i, i+1).unwrap();
-        }
-        assert!(path5.is_likely_traceable(false),
-                "Path graph P5 should be identified as traceable");
+    fn test_regularity() {
+        let petersen = Graph::petersen();
+        assert!(petersen.is_regular());
+        assert_eq!(petersen.regularity(), Some(3));
 
-        let mut star5 = Graph::new(5);
-        for i in 1..5 {
-            star5.add_edge(0, i).unwrap();
-        }
-        assert!(star5.is_likely_traceable(false),
-                "Star graph K_{{1,4}} should be identified as traceable");
+        let star = Graph::star(5);
+        assert!(!star.is_regular());
+        assert_eq!(star.regularity(), None);
 
-        // The simplest traceable graph is a path
-        // Let's create a path and verify the implementation identifies it correctly
-        let mut simple_path = Graph::new(10);
-        for i in 0..9 {
-            simple_path.add_edge(i, i+1).unwrap();
-        }
+        assert_eq!(Graph::new(3).regularity(), Some(0));
+        assert!(!Graph::new(0).is_regular());
+    }
 
-        let simple_path_traceable = simple_path.is_likely_traceable(false);
-        println!("Simple path P10 is traceable according to implementation: {}",
-                 simple_path_traceable);
+    #[test]
+    fn test_hyper_and_modified_zagreb_indices() {
+        // C5: five edges with degree sum 4, five vertices of degree 2
+        let c5 = Graph::cycle(5);
+        assert_eq!(c5.hyper_zagreb_index(), 5 * 16);
+        assert!((c5.modified_first_zagreb_index() - 5.0 / 4.0).abs() < 1e-12);
+
+        // K4: six edges with degree sum 6, four vertices of degree 3
+        let k4 = Graph::complete(4);
+        assert_eq!(k4.hyper_zagreb_index(), 6 * 36);
+        assert!((k4.modified_first_zagreb_index() - 4.0 / 9.0).abs() < 1e-12);
+
+        // Isolated vertices contribute nothing
+        let with_isolated = Graph::from_edges(3, [(0, 1)]).unwrap();
+        assert_eq!(with_isolated.modified_first_zagreb_index(), 2.0);
+    }
 
-        assert!(simple_path_traceable,
-                "A simple path graph P10 should be identified as traceable");
+    #[test]
+    fn test_contract_edge() {
+        let mut triangle = Graph::cycle(3);
+
+        // Vertex 1 merges into 0, so 0-2 and 1-2 collapse into one edge; old vertex 2 becomes 1
+        triangle.contract_edge(0, 1).unwrap();
+        assert_eq!(triangle.vertex_count(), 2);
+        assert_eq!(triangle.edge_count(), 1);
+        assert_eq!(triangle.edge_list(), vec![(0, 1)]);
+
+        // Contracting the middle edge of P4 gives P3, with weights carried over
+        let mut path = Graph::from_edges(4, [(0, 1), (1, 2)]).unwrap();
+        path.add_weighted_edge(2, 3, 2.5).unwrap();
+        path.contract_edge(1, 2).unwrap();
+        assert_eq!(path.edge_list(), vec![(0, 1), (1, 2)]);
+        assert_eq!(path.edge_weight(1, 2), Some(2.5));
+
+        assert!(matches!(path.contract_edge(0, 2), Err(GraphError::InvalidInput(_))));
+        assert_eq!(path.contract_edge(1, 1), Err(GraphError::SelfLoop(1)));
+        assert_eq!(path.contract_edge(0, 7), Err(GraphError::VertexOutOfBounds(7)));
+    }
 
-        // Now let's test a more complex graph where we add edges to the path
-        // but make sure it remains traceable
-        let mut complex_path = Graph::new(10);
+    #[test]
+    fn test_edge_csv_round_trip() {
+        let petersen = Graph::petersen();
+
+        let csv = petersen.to_edge_csv();
+        assert!(csv.starts_with("source,target\n0,1\n"));
+        assert_eq!(csv.lines().count(), 16);
+        assert_eq!(Graph::from_edge_csv(10, &csv).unwrap(), petersen);
+
+        // Quoted fields, padding, blank lines and a missing header are all accepted
+        let loose = "\"0\", \"1\"\n\n 1 ,2\r\n";
+        let path = Graph::from_edge_csv(3, loose).unwrap();
+        assert_eq!(path.edge_list(), vec![(0, 1), (1, 2)]);
+
+        assert!(matches!(Graph::from_edge_csv(3, "source,target\n0,x\n"), Err(GraphError::InvalidInput(_))));
+        assert!(matches!(Graph::from_edge_csv(3, "0,1,2\n"), Err(GraphError::InvalidInput(_))));
+        assert_eq!(Graph::from_edge_csv(3, "0,5\n"), Err(GraphError::VertexOutOfBounds(5)));
+    }
 
-        // Base path to ensure traceability
-        for i in 0..9 {
-            complex_path.add_edge(i, i+1).unwrap();
+    #[test]
+    fn test_from_edges() {
+        let mut looped = Graph::new(5);
+        for i in 0..5 {
+            for j in (i + 1)..5 {
+                looped.add_edge(i, j).unwrap();
+            }
         }
 
-        // Add a few strategically placed edges that don't affect traceability
-        complex_path.add_edge(0, 2).unwrap();
-        complex_path.add_edge(2, 4).unwrap();
-        complex_path.add_edge(4, 6).unwrap();
-        complex_path.add_edge(6, 8).unwrap();
-
-        let k = 1;
-        let n = complex_path.vertex_count();
-        let e = complex_path.edge_count();
-        let delta = complex_path.min_degree();
-        let delta_max = complex_path.max_degree();
-        let z1 = complex_path.first_zagreb_index();
+        let k5 = Graph::from_edges(5, (0..5).flat_map(|i| ((i + 1)..5).map(move |j| (i, j)))).unwrap();
+        assert_eq!(k5, looped);
+        assert_eq!(k5.edge_count(), 10);
 
-        // Calculate Theorem 2 threshold
-        let part1 = (n - k - 2) * delta_max * delta_max;
-        let part2 = (e * e) / (k + 2);
-        let part3 = ((n - k - 2) as f64).sqrt() - (delta as f64).sqrt();
-        let part3_squared = part3 * part3;
-        let threshold = part1 + part2 + (part3_squared * e as f64) as usize;
+        // Repeated edges in either orientation are added once
+        let single = Graph::from_edges(2, [(0, 1), (1, 0), (0, 1)]).unwrap();
+        assert_eq!(single.edge_count(), 1);
 
-        println!("Theorem 2 test with complex path: n={}, k={}, e={}, delta={}, delta_max={}",
-                 n, k, e, delta, delta_max);
-        println!("Theorem 2 test: Zagreb index = {}, threshold = {}", z1, threshold);
+        assert_eq!(Graph::from_edges(3, [(0, 1), (1, 3)]), Err(GraphError::VertexOutOfBounds(3)));
+        assert_eq!(Graph::from_edges(3, vec![(2, 2)]), Err(GraphError::SelfLoop(2)));
+    }
 
-        let complex_path_traceable = complex_path.is_likely_traceable(false);
-        println!("Complex path is traceable according to implementation: {}",
-                 complex_path_traceable);
+    #[test]
+    fn test_likely_hamiltonian_default_wrappers() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
 
-        // Check with exact connectivity calculation as well
-        let complex_path_traceable_exact = complex_path.is_likely_traceable(true);
-        println!("Complex path is traceable with exact connectivity check: {}",
-                 complex_path_traceable_exact);
+        let petersen = Graph::petersen();
 
-        // Print other relevant information
-        println!("Complex path is 1-connected: {}", complex_path.is_k_connected(1, false));
-        println!("Complex path is identified as a path: {}", complex_path.is_path());
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut graphs = vec![petersen, Graph::wheel(6), Graph::grid(3, 3)];
+        graphs.extend((0..10).map(|_| Graph::erdos_renyi(9, 0.5, &mut rng)));
 
-        // Instead of strict assertion, print diagnostic information if the implementation
-        // doesn't behave as expected
-        if !complex_path_traceable {
-            println!("WARNING: The implementation doesn't identify a complex path as traceable");
-            println!("This may indicate an issue with the traceable detection algorithm");
+        for graph in &graphs {
+            assert_eq!(graph.is_likely_hamiltonian(), graph.is_likely_hamiltonian_with(false));
+            assert_eq!(graph.is_likely_traceable(), graph.is_likely_traceable_with(false));
         }
+    }
 
-        // Test special case: K_{k,k+2}
-        // For k=1, K_{1,3} is actually traceable even though it's the form K_{k,k+2}
-        let mut small_bipartite = Graph::new(4);
-        small_bipartite.add_edge(0, 1).unwrap();
-        small_bipartite.add_edge(0, 2).unwrap();
-        small_bipartite.add_edge(0, 3).unwrap();
-
-        let small_bipartite_traceable = small_bipartite.is_likely_traceable(false);
-        println!("K_{{1,3}} bipartite graph is traceable according to implementation: {}",
-                 small_bipartite_traceable);
+    #[test]
+    fn test_is_k_connected_default_wrapper() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
 
-        assert!(small_bipartite_traceable,
-                "K_{{1,3}} bipartite graph should be identified as traceable");
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut graphs = vec![Graph::wheel(7), Graph::grid(3, 4)];
+        graphs.extend((0..10).map(|_| Graph::erdos_renyi(10, 0.6, &mut rng)));
 
-        // For a better test, use k=2 where K_{2,4} is mentioned in the paper
-        let mut bipartite = Graph::new(6);
-        // Connect vertices 0,1 to vertices 2,3,4,5
-        for i in 0..2 {
-            for j in 2..6 {
-                bipartite.add_edge(i, j).unwrap();
+        for graph in &graphs {
+            for k in 1..5 {
+                assert_eq!(graph.is_k_connected(k), graph.is_k_connected_with(k, false));
             }
         }
+    }
 
-        let bipartite_traceable = bipartite.is_likely_traceable(false);
-        println!("K_{{2,4}} bipartite graph is traceable according to implementation: {}",
-                 bipartite_traceable);
-
-        // No hard assertion here, just documenting whether the implementation handles the special case
-        println!("K_{{2,4}} is 2-connected: {}", bipartite.is_k_connected(2, false));
+    #[test]
+    fn test_is_k_edge_connected() {
+        let c6 = Graph::cycle(6);
+        assert!(c6.is_k_edge_connected(2));
+        assert!(!c6.is_k_edge_connected(3));
+
+        let tree = Graph::from_edges(5, [(0, 1), (0, 2), (2, 3), (2, 4)]).unwrap();
+        assert!(tree.is_k_edge_connected(1));
+        assert!(!tree.is_k_edge_connected(2));
+
+        let k4 = Graph::complete(4);
+        assert!(k4.is_k_edge_connected(3));
+        assert!(!k4.is_k_edge_connected(4));
+
+        // Two triangles sharing a vertex: 2-edge-connected but only 1-vertex-connected
+        let bowtie =
+            Graph::from_edges(5, [(0, 1), (1, 2), (2, 0), (0, 3), (3, 4), (4, 0)]).unwrap();
+        assert!(bowtie.is_k_edge_connected(2));
+        assert!(!bowtie.is_k_connected_with(2, true));
+        assert_eq!(bowtie.edge_connectivity(), 2);
+
+        assert!(Graph::new(3).is_k_edge_connected(0));
+        assert!(!Graph::new(3).is_k_edge_connected(1));
+    }
 
-        // Create and test a cycle graph which is both Hamiltonian and traceable
-        let mut cycle = Graph::new(10);
-        for i in 0..10 {
-            cycle.add_edge(i, (i+1) % 10).unwrap();
+    #[test]
+    fn test_minimum_vertex_cut() {
+        // Two K4s whose only link is vertex 4, attached to two vertices on each side
+        let mut barbell = Graph::new(9);
+        for base in [0, 5] {
+            for i in 0..4 {
+                for j in (i + 1)..4 {
+                    barbell.add_edge(base + i, base + j).unwrap();
+                }
+            }
+        }
+        for v in [2, 3, 5, 6] {
+            barbell.add_edge(4, v).unwrap();
         }
 
-        let cycle_traceable = cycle.is_likely_traceable(false);
-        println!("Cycle C10 is traceable according to implementation: {}", cycle_traceable);
-
-        assert!(cycle_traceable, "Cycle graph C10 should be identified as traceable");
+        assert_eq!(barbell.minimum_vertex_cut(0, 8), vec![4]);
+        assert_eq!(barbell.minimum_vertex_cut(8, 0), vec![4]);
+
+        // Opposite vertices of C6 are separated by the two neighbors of s
+        let c6 = Graph::cycle(6);
+        let cut = c6.minimum_vertex_cut(0, 3);
+        assert_eq!(cut, vec![1, 5]);
+
+        // Removing the cut disconnects s from t
+        let (remaining, mapping) = c6
+            .induced_subgraph(&(0..6).filter(|v| !cut.contains(v)).collect::<Vec<_>>())
+            .unwrap();
+        let s = mapping.iter().position(|&v| v == 0).unwrap();
+        let t = mapping.iter().position(|&v| v == 3).unwrap();
+        assert_eq!(remaining.distance(s, t), None);
+
+        // Adjacent or already disconnected pairs have no cut
+        assert!(c6.minimum_vertex_cut(0, 1).is_empty());
+        assert!(Graph::new(3).minimum_vertex_cut(0, 2).is_empty());
     }
 
     #[test]
-    fn test_theorem_3_upper_bound() {
-        // Theorem 3 deals with upper bounds for the Zagreb index
+    fn test_maximum_matching() {
+        let path = Graph::path(4);
+        assert_eq!(path.maximum_matching(), vec![(0, 1), (2, 3)]);
+
+        let c6 = Graph::cycle(6);
+        assert_eq!(c6.maximum_matching().len(), 3);
+
+        // Odd cycles and the Petersen graph go through the blossom search
+        let c5 = Graph::cycle(5);
+        assert_eq!(c5.maximum_matching().len(), 2);
+
+        let petersen = Graph::petersen();
+        let matching = petersen.maximum_matching();
+        assert_eq!(matching.len(), 5);
+
+        // The edges exist and share no endpoints
+        let mut covered = HashSet::new();
+        for &(u, v) in &matching {
+            assert!(petersen.edge_list().contains(&(u, v)));
+            assert!(covered.insert(u) && covered.insert(v));
+        }
 
-        // Test on various graph types to verify the upper bound holds
+        // Two triangles joined by an edge: a greedy start must be repaired through a blossom
+        let graph =
+            Graph::from_edges(6, [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)]).unwrap();
+        assert_eq!(graph.maximum_matching().len(), 3);
+    }
 
-        // Test on a complete graph K_5
-        let mut complete = Graph::new(5);
-        for i in 0..4 {
-            for j in (i+1)..5 {
-                complete.add_edge(i, j).unwrap();
+    #[test]
+    fn test_matching_algorithms_agree() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(17);
+        for _ in 0..20 {
+            // Random bipartite graph between 0..6 and 6..12
+            let mut bipartite = Graph::new(12);
+            for u in 0..6 {
+                for v in 6..12 {
+                    if rng.random_bool(0.3) {
+                        bipartite.add_edge(u, v).unwrap();
+                    }
+                }
+            }
+            let left: Vec<usize> = (0..6).collect();
+            let hopcroft_karp = bipartite.hopcroft_karp(&left).iter().flatten().count() / 2;
+            let blossom = bipartite.blossom_matching().iter().flatten().count() / 2;
+            assert_eq!(hopcroft_karp, blossom);
+
+            let graph = Graph::erdos_renyi(12, 0.3, &mut rng);
+
+            // No matching is larger than the one found, checked against all edge subsets
+            // via the independence number of the line graph
+            let (line, _) = graph.line_graph();
+            if line.vertex_count() <= 30 {
+                assert_eq!(graph.maximum_matching().len(), line.independence_number_exact());
             }
         }
+    }
 
-        // Calculate actual Zagreb index
-        let z1_complete = complete.first_zagreb_index();
+    #[test]
+    fn test_has_edge() {
+        let triangle = Graph::from_edges(4, [(0, 1), (1, 2), (2, 0)]).unwrap();
+
+        assert!(triangle.has_edge(0, 1));
+        assert!(triangle.has_edge(1, 0));
+        assert!(triangle.has_edge(2, 0));
+        assert!(!triangle.has_edge(0, 3));
+        assert!(!triangle.has_edge(1, 1));
+
+        // Out-of-bounds vertices are simply not adjacent
+        assert!(!triangle.has_edge(0, 9));
+        assert!(!triangle.has_edge(9, 0));
+    }
 
-        // Calculate upper bound using Theorem 3
-        let upper_bound_complete = complete.zagreb_upper_bound();
+    #[test]
+    fn test_named_constructors() {
+        let path = Graph::path(5);
+        assert_eq!(path.edge_count(), 4);
+        assert!(path.is_path());
 
-        // The Zagreb index should not exceed the upper bound
-        assert!(z1_complete as f64 <= upper_bound_complete,
-                "Zagreb index {} should not exceed upper bound {} for complete graph",
-                z1_complete, upper_bound_complete);
+        let cycle = Graph::cycle(6);
+        assert_eq!(cycle.edge_count(), 6);
+        assert!(cycle.is_cycle());
 
-        println!("K_5: Zagreb index = {}, upper bound = {}",
-                 z1_complete, upper_bound_complete);
+        let star = Graph::star(5);
+        assert_eq!(star.edge_count(), 4);
+        assert!(star.is_star());
+        assert_eq!(star.degree(0), Ok(4));
 
-        // Test on a cycle graph C_6
-        let mut cycle = Graph::new(6);
-        for i in 0..6 {
-            cycle.add_edge(i, (i+1) % 6).unwrap();
-        }
+        let complete = Graph::complete(5);
+        assert_eq!(complete.edge_count(), 10);
+        assert!(complete.is_complete());
 
-        let z1_cycle = cycle.first_zagreb_index();
-        let upper_bound_cycle = cycle.zagreb_upper_bound();
+        let petersen = Graph::petersen();
+        assert_eq!(petersen.edge_count(), 15);
+        assert!(petersen.is_petersen());
 
-        // The Zagreb index should not exceed the upper bound
-        assert!(z1_cycle as f64 <= upper_bound_cycle,
-                "Zagreb index {} should not exceed upper bound {} for cycle graph",
-                z1_cycle, upper_bound_cycle);
+        // Degenerate sizes
+        assert_eq!(Graph::path(1).edge_count(), 0);
+        assert_eq!(Graph::complete(0).vertex_count(), 0);
+    }
 
-        println!("C_6: Zagreb index = {}, upper bound = {}",
-                 z1_cycle, upper_bound_cycle);
+    #[test]
+    #[should_panic(expected = "A cycle needs at least 3 vertices")]
+    fn test_cycle_too_small() {
+        Graph::cycle(2);
+    }
 
-        // Test on a star graph K_{1,5}
-        let mut star = Graph::new(6);
-        for i in 1..6 {
-            star.add_edge(0, i).unwrap();
-        }
+    #[test]
+    fn test_degree_cache_invalidated_on_mutation() {
+        let mut graph = Graph::path(4);
+        assert_eq!(graph.max_degree(), 2);
+        assert_eq!(graph.first_zagreb_index(), 10);
 
-        let z1_star = star.first_zagreb_index();
-        let upper_bound_star = star.zagreb_upper_bound();
+        // Vertex 1 gains a third neighbor
+        graph.add_edge(1, 3).unwrap();
+        assert_eq!(graph.max_degree(), 3);
+        assert_eq!(graph.min_degree(), 1);
+        assert_eq!(graph.first_zagreb_index(), 1 + 9 + 4 + 4);
 
-        // The Zagreb index should not exceed the upper bound
-        assert!(z1_star as f64 <= upper_bound_star,
-                "Zagreb index {} should not exceed upper bound {} for star graph",
-                z1_star, upper_bound_star);
+        // A clone carries the cached values but is updated independently
+        let mut copy = graph.clone();
+        copy.add_edge(0, 2).unwrap();
+        assert_eq!(copy.min_degree(), 2);
+        assert_eq!(graph.min_degree(), 1);
 
-        println!("K_{{1,5}}: Zagreb index = {}, upper bound = {}",
-                 z1_star, upper_bound_star);
+        graph.contract_edge(1, 3).unwrap();
+        assert_eq!(graph.max_degree(), 2);
 
-        // Test on a bipartite graph K_{m,n}
-        let mut bipartite = Graph::new(6);
-        // Create K_{2,4} with vertices 0,1 connected to vertices 2,3,4,5
-        for i in 0..2 {
-            for j in 2..6 {
-                bipartite.add_edge(i, j).unwrap();
+        graph.remove_vertex(0).unwrap();
+        assert_eq!(graph.max_degree(), 1);
+
+        graph.clear_edges();
+        assert_eq!(graph.max_degree(), 0);
+        assert_eq!(graph.first_zagreb_index(), 0);
+    }
+
+    #[test]
+    fn test_is_isomorphic() {
+        // The pentagram i -- i + 2 is C5 under a different labeling
+        let c5 = Graph::cycle(5);
+        let mut pentagram = Graph::new(5);
+        for i in 0..5 {
+            pentagram.add_edge(i, (i + 2) % 5).unwrap();
+        }
+        assert!(c5.is_isomorphic(&pentagram));
+        assert!(pentagram.is_isomorphic(&c5));
+
+        // K_{2,3} shares the vertex count but not the degree sequence
+        let mut k23 = Graph::new(5);
+        for u in 0..2 {
+            for v in 2..5 {
+                k23.add_edge(u, v).unwrap();
             }
         }
+        assert!(!c5.is_isomorphic(&k23));
 
-        let z1_bipartite = bipartite.first_zagreb_index();
-        let upper_bound_bipartite = bipartite.zagreb_upper_bound();
+        // Same degree sequence, different structure: C6 against two triangles
+        let triangles =
+            Graph::from_edges(6, [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]).unwrap();
+        assert!(!Graph::cycle(6).is_isomorphic(&triangles));
+    }
 
-        // The Zagreb index should not exceed the upper bound
-        assert!(z1_bipartite as f64 <= upper_bound_bipartite,
-                "Zagreb index {} should not exceed upper bound {} for bipartite graph",
-                z1_bipartite, upper_bound_bipartite);
+    #[test]
+    fn test_incremental_zagreb_matches_recomputation() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut graph = Graph::new(60);
+        for _ in 0..2000 {
+            let u = rng.random_range(0..60);
+            let v = rng.random_range(0..60);
+            // Self-loops and repeated edges are rejected or ignored without changing the index
+            let _ = graph.add_edge(u, v);
+            assert_eq!(graph.first_zagreb_index(), squared_degree_sum(&graph.edges));
+        }
 
-        println!("K_{{2,4}}: Zagreb index = {}, upper bound = {}",
-                 z1_bipartite, upper_bound_bipartite);
+        graph.remove_vertex(7).unwrap();
+        assert_eq!(graph.first_zagreb_index(), squared_degree_sum(&graph.edges));
 
-        // Test on a Petersen graph (known to have specific properties)
-        let mut petersen = Graph::new(10);
-        // Add outer cycle
-        petersen.add_edge(0, 1).unwrap();
-        petersen.add_edge(1, 2).unwrap();
-        petersen.add_edge(2, 3).unwrap();
-        petersen.add_edge(3, 4).unwrap();
-        petersen.add_edge(4, 0).unwrap();
-        // Add spokes
-        petersen.add_edge(0, 5).unwrap();
-        petersen.add_edge(1, 6).unwrap();
-        petersen.add_edge(2, 7).unwrap();
-        petersen.add_edge(3, 8).unwrap();
-        petersen.add_edge(4, 9).unwrap();
-        // Add inner pentagram
-        petersen.add_edge(5, 7).unwrap();
-        petersen.add_edge(7, 9).unwrap();
-        petersen.add_edge(9, 6).unwrap();
-        petersen.add_edge(6, 8).unwrap();
-        petersen.add_edge(8, 5).unwrap();
+        let v = *graph.edges[0].iter().next().unwrap();
+        graph.contract_edge(0, v).unwrap();
+        assert_eq!(graph.first_zagreb_index(), squared_degree_sum(&graph.edges));
 
-        let z1_petersen = petersen.first_zagreb_index();
-        let upper_bound_petersen = petersen.zagreb_upper_bound();
+        graph.clear_edges();
+        assert_eq!(graph.first_zagreb_index(), 0);
+    }
 
-        // The Zagreb index should not exceed the upper bound
-        assert!(z1_petersen as f64 <= upper_bound_petersen,
-                "Zagreb index {} should not exceed upper bound {} for Petersen graph",
-                z1_petersen, upper_bound_petersen);
+    #[test]
+    fn test_cartesian_product() {
+        let grid = Graph::path(3).cartesian_product(&Graph::path(3));
+        assert!(grid.is_isomorphic(&Graph::grid(3, 3)));
+
+        // C4 □ C4 is the 4-regular torus, isomorphic to the 4-dimensional hypercube
+        let torus = Graph::cycle(4).cartesian_product(&Graph::cycle(4));
+        assert_eq!(torus.vertex_count(), 16);
+        assert_eq!(torus.edge_count(), 32);
+        assert_eq!(torus.regularity(), Some(4));
+        let k2 = Graph::path(2);
+        let hypercube = k2
+            .cartesian_product(&k2)
+            .cartesian_product(&k2)
+            .cartesian_product(&k2);
+        assert!(torus.is_isomorphic(&hypercube));
+
+        // The empty graph on no vertices annihilates the product
+        assert_eq!(Graph::cycle(5).cartesian_product(&Graph::new(0)).vertex_count(), 0);
+    }
 
-        println!("Petersen: Zagreb index = {}, upper bound = {}",
-                 z1_petersen, upper_bound_petersen);
+    #[test]
+    fn test_spectral_radius() {
+        assert!((Graph::petersen().spectral_radius() - 3.0).abs() < 1e-6);
+        assert!((Graph::complete(5).spectral_radius() - 4.0).abs() < 1e-6);
+
+        // Bipartite: the star K_{1,4} has spectrum ±2 and 0, and must not oscillate
+        assert!((Graph::star(5).spectral_radius() - 2.0).abs() < 1e-6);
+        assert!((Graph::path(2).spectral_radius() - 1.0).abs() < 1e-6);
+        assert_eq!(Graph::new(4).spectral_radius(), 0.0);
+
+        // Agrees with the dense eigenvalue solver on an irregular, disconnected graph
+        let graph = Graph::star(4).cartesian_product(&Graph::path(2));
+        let mut with_isolated = Graph::new(graph.vertex_count() + 1);
+        for (u, v) in graph.edge_list() {
+            with_isolated.add_edge(u, v).unwrap();
+        }
+        let dense = with_isolated
+            .to_adjacency_matrix()
+            .into_iter()
+            .map(|row| row.into_iter().map(f64::from).collect())
+            .collect();
+        let largest = *symmetric_eigenvalues(dense).last().unwrap();
+        assert!((with_isolated.spectral_radius() - largest).abs() < 1e-6);
     }
 
     #[test]
-    fn test_graph_properties() {
-        // Test if the implementation correctly identifies various graph properties
+    fn test_algebraic_connectivity() {
+        // Path P_n has λ₂ = 2 - 2cos(π/n), complete K_n has λ₂ = n
+        let path = Graph::path(6).algebraic_connectivity();
+        let complete = Graph::complete(6).algebraic_connectivity();
+        assert!((path - (2.0 - 2.0 * (std::f64::consts::PI / 6.0).cos())).abs() < 1e-6);
+        assert!((complete - 6.0).abs() < 1e-6);
+        assert!(path < complete);
+
+        // C6 has λ₂ = 2 - 2cos(2π/6) = 1, the Petersen graph has λ₂ = 2
+        assert!((Graph::cycle(6).algebraic_connectivity() - 1.0).abs() < 1e-6);
+        assert!((Graph::petersen().algebraic_connectivity() - 2.0).abs() < 1e-6);
+
+        let two_triangles = Graph::cycle(3).cartesian_product(&Graph::new(2));
+        assert_eq!(two_triangles.algebraic_connectivity(), 0.0);
+        assert_eq!(Graph::new(1).algebraic_connectivity(), 0.0);
+    }
 
-        // 1. Complete graph K_n
-        let mut complete5 = Graph::new(5);
-        for i in 0..4 {
-            for j in (i+1)..5 {
-                complete5.add_edge(i, j).unwrap();
+    #[test]
+    fn test_degree_extreme_vertices() {
+        let star = Graph::star(6);
+        assert_eq!(star.max_degree_vertex(), Some(0));
+        assert_eq!(star.min_degree_vertex(), Some(1));
+
+        // Ties resolve to the lowest index
+        let path = Graph::path(5);
+        assert_eq!(path.max_degree_vertex(), Some(1));
+        assert_eq!(path.min_degree_vertex(), Some(0));
+
+        assert_eq!(Graph::new(0).max_degree_vertex(), None);
+        assert_eq!(Graph::new(0).min_degree_vertex(), None);
+    }
+
+    #[test]
+    fn test_max_k_connectivity() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let petersen = Graph::petersen();
+        assert_eq!(petersen.max_k_connectivity(5), 3);
+        assert_eq!(petersen.max_k_connectivity(2), 2);
+        assert_eq!(Graph::complete(6).max_k_connectivity(10), 5);
+        assert_eq!(Graph::cycle(3).cartesian_product(&Graph::new(2)).max_k_connectivity(5), 0);
+
+        // Agrees with checking each k separately
+        let mut rng = StdRng::seed_from_u64(21);
+        for &p in &[0.3, 0.5, 0.8] {
+            let graph = Graph::erdos_renyi(12, p, &mut rng);
+            let expected = (1..=6).take_while(|&k| graph.is_k_connected_exact(k)).count();
+            assert_eq!(graph.max_k_connectivity(6), expected);
+        }
+    }
+
+    #[test]
+    fn test_independence_number_isolated_vertices() {
+        assert_eq!(Graph::new(0).independence_number_approx(), 0);
+        assert_eq!(Graph::new(4).independence_number_approx(), 4);
+
+        // K4 on vertices 0..4 plus three isolated vertices: every isolated vertex is taken,
+        // plus exactly one vertex of the clique
+        let mut mixed = Graph::new(7);
+        for u in 0..4 {
+            for v in (u + 1)..4 {
+                mixed.add_edge(u, v).unwrap();
             }
         }
+        assert_eq!(mixed.independence_number_approx(), 4);
 
-        // Expected properties for K_5
-        let is_complete = complete5.is_complete();
-        let is_hamiltonian = complete5.is_likely_hamiltonian(false);
-        let is_traceable = complete5.is_likely_traceable(false);
+        // A star's leaves beat its center; the isolated vertex adds one more
+        let mut star = Graph::new(6);
+        for leaf in 1..5 {
+            star.add_edge(0, leaf).unwrap();
+        }
+        assert_eq!(star.independence_number_approx(), 5);
+    }
 
-        println!("K_5: is_complete={}, is_hamiltonian={}, is_traceable={}",
-                 is_complete, is_hamiltonian, is_traceable);
+    #[test]
+    fn test_independence_number_tie_breaking() {
+        // Vertices 1-5 all have degree 2. Taking vertex 1 first (smallest index) leads to
+        // {1, 3, 4}; taking vertex 5 first would only reach a set of size 2.
+        let edges = [(0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (3, 5), (4, 5)];
+
+        // Each rebuild uses fresh hash sets and a different insertion order
+        for rotation in 0..edges.len() {
+            let mut rotated = edges;
+            rotated.rotate_left(rotation);
+            let graph = Graph::from_edges(6, rotated).unwrap();
+            for _ in 0..3 {
+                assert_eq!(graph.independence_number_approx(), 3);
+            }
+        }
+    }
 
-        assert!(is_complete, "K_5 should be identified as a complete graph");
-        assert!(is_hamiltonian, "K_5 should be identified as Hamiltonian");
-        assert!(is_traceable, "K_5 should be identified as traceable");
+    #[test]
+    fn test_independent_set_approx() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        assert_eq!(Graph::petersen().independent_set_approx().len(), 4);
+        assert_eq!(Graph::path(5).independent_set_approx(), vec![0, 2, 4]);
+        assert!(Graph::new(0).independent_set_approx().is_empty());
+
+        let mut rng = StdRng::seed_from_u64(5);
+        for &p in &[0.1, 0.3, 0.6] {
+            let graph = Graph::erdos_renyi(40, p, &mut rng);
+            let set = graph.independent_set_approx();
+            assert_eq!(set.len(), graph.independence_number_approx());
+
+            // No two members are adjacent, and no vertex is repeated
+            for (i, &u) in set.iter().enumerate() {
+                for &v in &set[i + 1..] {
+                    assert_ne!(u, v);
+                    assert!(!graph.has_edge(u, v));
+                }
+            }
+        }
+    }
 
-        // 2. Cycle graph C_n
-        let mut cycle6 = Graph::new(6);
-        for i in 0..6 {
-            cycle6.add_edge(i, (i+1) % 6).unwrap();
+    #[test]
+    fn test_dominating_set_approx() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        assert_eq!(Graph::star(6).dominating_set_approx(), vec![0]);
+        assert_eq!(Graph::path(5).dominating_set_approx(), vec![1, 3]);
+        assert!(Graph::new(0).dominating_set_approx().is_empty());
+
+        // Isolated vertices can only dominate themselves
+        assert_eq!(Graph::new(3).dominating_set_approx(), vec![0, 1, 2]);
+
+        let mut rng = StdRng::seed_from_u64(8);
+        for &p in &[0.05, 0.2, 0.5] {
+            let graph = Graph::erdos_renyi(40, p, &mut rng);
+            let set: HashSet<usize> = graph.dominating_set_approx().into_iter().collect();
+            for v in 0..graph.vertex_count() {
+                assert!(set.contains(&v) || graph.edges[v].iter().any(|u| set.contains(u)));
+            }
         }
+    }
 
-        // Expected properties for C_6
-        let is_cycle = cycle6.is_cycle();
-        let cycle_hamiltonian = cycle6.is_likely_hamiltonian(false);
-        let cycle_traceable = cycle6.is_likely_traceable(false);
+    #[test]
+    fn test_vertex_cover_approx() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        // A path on n vertices has a minimum vertex cover of size ⌊n / 2⌋
+        for n in 2..10 {
+            let path = Graph::path(n);
+            let cover: HashSet<usize> = path.vertex_cover_approx().into_iter().collect();
+            assert!(cover.len() <= 2 * (n / 2));
+            assert!(path.edge_list().iter().all(|(u, v)| cover.contains(u) || cover.contains(v)));
+        }
+        assert!(Graph::new(3).vertex_cover_approx().is_empty());
+
+        // The complement of a maximum independent set is a minimum vertex cover
+        let mut rng = StdRng::seed_from_u64(13);
+        for &p in &[0.2, 0.4, 0.7] {
+            let graph = Graph::erdos_renyi(14, p, &mut rng);
+            let cover: HashSet<usize> = graph.vertex_cover_approx().into_iter().collect();
+            let optimum = graph.vertex_count() - graph.independence_number_exact();
+            assert!(cover.len() <= 2 * optimum);
+            assert!(graph.edge_list().iter().all(|(u, v)| cover.contains(u) || cover.contains(v)));
+        }
+    }
 
-        println!("C_6: is_cycle={}, is_hamiltonian={}, is_traceable={}",
-                 is_cycle, cycle_hamiltonian, cycle_traceable);
+    #[test]
+    fn test_edges_between() {
+        // Two K4s joined by the single edge 3 -- 4
+        let k4 = Graph::complete(4).edge_list();
+        let cliques = [0, 4].into_iter().flat_map(|o| k4.iter().map(move |&(u, v)| (u + o, v + o)));
+        let barbell = Graph::from_edges(8, cliques.chain([(3, 4)])).unwrap();
+
+        let left = [0, 1, 2, 3];
+        let right = [4, 5, 6, 7];
+        assert_eq!(barbell.edges_between(&left, &right), 1);
+        assert_eq!(barbell.edges_between(&right, &left), 1);
+
+        // Overlapping sets count each qualifying edge once
+        assert_eq!(barbell.edges_between(&left, &left), 6);
+        let all: Vec<usize> = (0..8).collect();
+        assert_eq!(barbell.edges_between(&all, &all), barbell.edge_count());
+
+        // Repeats and out-of-bounds vertices are ignored; only 2 -- 3 and 3 -- 4 qualify
+        assert_eq!(barbell.edges_between(&[2, 3, 3], &[3, 4, 9]), 2);
+        assert_eq!(barbell.edges_between(&[], &all), 0);
+    }
 
-        assert!(is_cycle, "C_6 should be identified as a cycle graph");
-        assert!(cycle_hamiltonian, "C_6 should be identified as Hamiltonian");
-        assert!(cycle_traceable, "C_6 should be identified as traceable");
+    #[test]
+    fn test_louvain_communities() {
+        // Three K5s joined in a ring by single edges 4 -- 5, 9 -- 10 and 14 -- 0
+        let k5 = Graph::complete(5).edge_list();
+        let cliques = [0, 5, 10]
+            .into_iter()
+            .flat_map(|o| k5.iter().map(move |&(u, v)| (u + o, v + o)));
+        let graph = Graph::from_edges(15, cliques.chain([(4, 5), (9, 10), (14, 0)])).unwrap();
+
+        let expected: Vec<Vec<usize>> = [0, 5, 10].iter().map(|&o| (o..o + 5).collect()).collect();
+        assert_eq!(graph.louvain_communities(), expected);
+
+        // Components are never merged, and edgeless graphs stay as singletons
+        let two_triangles = Graph::cycle(3).cartesian_product(&Graph::new(2));
+        assert_eq!(two_triangles.louvain_communities().len(), 2);
+        assert_eq!(Graph::new(3).louvain_communities(), vec![vec![0], vec![1], vec![2]]);
+        assert!(Graph::new(0).louvain_communities().is_empty());
+
+        // Every vertex lands in exactly one community
+        let mut seen: Vec<usize> = Graph::petersen().louvain_communities().concat();
+        seen.sort_unstable();
+        assert_eq!(seen, (0..10).collect::<Vec<_>>());
+    }
 
-        // 3. Path graph P_n
-        let mut path5 = Graph::new(5);
-        for i in 0..4 {
-            path5.add_edge(i, i+1).unwrap();
+    #[test]
+    fn test_is_planar() {
+        let k2 = Graph::path(2);
+        let cube = k2.cartesian_product(&k2).cartesian_product(&k2);
+        assert!(cube.is_planar());
+        assert!(!Graph::complete(5).is_planar());
+
+        let mut k33 = Graph::new(6);
+        for u in 0..3 {
+            for v in 3..6 {
+                k33.add_edge(u, v).unwrap();
+            }
+        }
+        assert!(!k33.is_planar());
+
+        // Removing an edge of K5 or K3,3 makes them planar
+        let k5_edges = Graph::complete(5).edge_list();
+        let k5_minus_edge = Graph::from_edges(5, k5_edges.iter().cloned().skip(1)).unwrap();
+        assert!(k5_minus_edge.is_planar());
+        let k33_minus_edge = Graph::from_edges(6, k33.edge_list().into_iter().skip(1)).unwrap();
+        assert!(k33_minus_edge.is_planar());
+
+        // Sparse enough to pass Euler's bounds, but contain K5 and K3,3 minors
+        assert!(!Graph::petersen().is_planar());
+        let mut subdivided_k5 = Graph::new(15);
+        for (i, &(u, v)) in k5_edges.iter().enumerate() {
+            subdivided_k5.add_edge(u, 5 + i).unwrap();
+            subdivided_k5.add_edge(5 + i, v).unwrap();
+        }
+        assert!(!subdivided_k5.is_planar());
+
+        // Planar families, including a disconnected union and graphs with cut vertices
+        assert!(Graph::grid(5, 5).is_planar());
+        assert!(Graph::wheel(8).is_planar());
+        assert!(Graph::star(7).is_planar());
+        assert!(Graph::cycle(4).cartesian_product(&Graph::new(3)).is_planar());
+        assert!(Graph::new(0).is_planar());
+
+        // Stacked triangulations are maximal planar: 3n - 6 edges, and adding any edge breaks it
+        let mut stacked = Graph::complete(3);
+        let mut triangles = vec![(0, 1, 2)];
+        for v in 3..30 {
+            let (a, b, c) = triangles[v % triangles.len()];
+            let mut grown = Graph::new(v + 1);
+            for (x, y) in stacked.edge_list() {
+                grown.add_edge(x, y).unwrap();
+            }
+            for u in [a, b, c] {
+                grown.add_edge(u, v).unwrap();
+            }
+            triangles.extend([(a, b, v), (b, c, v), (a, c, v)]);
+            stacked = grown;
         }
+        assert_eq!(stacked.edge_count(), 3 * 30 - 6);
+        assert!(stacked.is_planar());
+    }
 
-        // Expected properties for P_5
-        let is_path = path5.is_path();
-        let path_hamiltonian = path5.is_likely_hamiltonian(false);
-        let path_traceable = path5.is_likely_traceable(false);
+    #[test]
+    fn test_display() {
+        let petersen = Graph::petersen();
+        assert_eq!(petersen.to_string(), "Graph(n=10, m=15, M1=90)");
+        assert_eq!(Graph::new(0).to_string(), "Graph(n=0, m=0, M1=0)");
+
+        // Display stays on one line, unlike the adjacency dump from Debug
+        assert!(!petersen.to_string().contains('\n'));
+        assert!(format!("{:?}", petersen).contains("adjacency list"));
+    }
 
-        println!("P_5: is_path={}, is_hamiltonian={}, is_traceable={}",
-                 is_path, path_hamiltonian, path_traceable);
+    #[test]
+    fn test_vertices_by_degree() {
+        let star = Graph::star(5);
+        assert_eq!(star.vertices_by_degree(true), vec![1, 2, 3, 4, 0]);
+        assert_eq!(star.vertices_by_degree(false), vec![0, 1, 2, 3, 4]);
+
+        // Ties keep increasing index in both directions
+        let path = Graph::path(4);
+        assert_eq!(path.vertices_by_degree(true), vec![0, 3, 1, 2]);
+        assert_eq!(path.vertices_by_degree(false), vec![1, 2, 0, 3]);
+        assert!(Graph::new(0).vertices_by_degree(true).is_empty());
+    }
 
-        assert!(is_path, "P_5 should be identified as a path graph");
-        assert!(!path_hamiltonian, "P_5 should not be identified as Hamiltonian");
-        assert!(path_traceable, "P_5 should be identified as traceable");
+    #[test]
+    fn test_k_core() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let petersen = Graph::petersen();
+        assert_eq!(petersen.k_core(3), (0..10).collect::<Vec<_>>());
+        assert!(petersen.k_core(4).is_empty());
+        assert_eq!(petersen.core_number(), vec![3; 10]);
+
+        // K4 with a pendant path 3 -- 4 -- 5 hanging off it
+        let pendant = [(3, 4), (4, 5)];
+        let graph = Graph::from_edges(6, Graph::complete(4).edge_list().into_iter().chain(pendant))
+            .unwrap();
+        assert_eq!(graph.core_number(), vec![3, 3, 3, 3, 1, 1]);
+        assert_eq!(graph.k_core(2), vec![0, 1, 2, 3]);
+        assert_eq!(graph.k_core(0), (0..6).collect::<Vec<_>>());
+        assert!(Graph::new(0).core_number().is_empty());
+
+        // Agrees with repeatedly deleting vertices of degree below k
+        let mut rng = StdRng::seed_from_u64(17);
+        let random = Graph::erdos_renyi(60, 0.1, &mut rng);
+        for k in 0..8 {
+            let mut alive = [true; 60];
+            loop {
+                let low = (0..60).find(|&v| {
+                    alive[v] && random.edges[v].iter().filter(|&&u| alive[u]).count() < k
+                });
+                match low {
+                    Some(v) => alive[v] = false,
+                    None => break,
+                }
+            }
+            let expected: Vec<usize> = (0..60).filter(|&v| alive[v]).collect();
+            assert_eq!(random.k_core(k), expected);
+        }
+    }
 
-        // 4. Star graph K_{1,n}
-        let mut star5 = Graph::new(5);
-        for i in 1..5 {
-            star5.add_edge(0, i).unwrap();
+    #[test]
+    fn test_edge_csv_malformed_first_row() {
+        // A first row with one numeric field is a broken edge, not a header
+        assert!(matches!(Graph::from_edge_csv(3, "0,x\n1,2"), Err(GraphError::InvalidInput(_))));
+        assert!(matches!(Graph::from_edge_csv(3, "x,2\n0,1"), Err(GraphError::InvalidInput(_))));
+
+        let graph = Graph::from_edge_csv(3, "from,to\n1,2").unwrap();
+        assert_eq!(graph.edge_list(), vec![(1, 2)]);
+    }
+
+    #[cfg(feature = "petgraph")]
+    #[test]
+    fn test_petgraph_round_trip() {
+        let cycle = Graph::cycle(6);
+        let converted = cycle.to_petgraph();
+        assert_eq!(converted.node_count(), 6);
+        assert_eq!(converted.edge_count(), 6);
+        assert_eq!(Graph::from_petgraph(&converted), Ok(cycle));
+
+        let mut looped = petgraph::graph::UnGraph::<(), ()>::new_undirected();
+        let a = looped.add_node(());
+        looped.add_edge(a, a, ());
+        assert_eq!(Graph::from_petgraph(&looped), Err(GraphError::SelfLoop(0)));
+    }
+}
+
+#[cfg(test)]
+mod connectivity_property_tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    /// Compare `is_k_connected_approx` with `is_k_connected_exact` on seeded random graphs
+    /// Returns the number of samples and the graphs on which the approximation was wrong
+    fn compare_approx_with_exact(k: usize, seed: u64) -> (usize, Vec<Graph>, Vec<Graph>) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut samples = 0;
+        let mut false_positives = Vec::new();
+        let mut false_negatives = Vec::new();
+
+        for n in 5..=8 {
+            for &p in &[0.3, 0.5, 0.7] {
+                for _ in 0..100 {
+                    let graph = Graph::erdos_renyi(n, p, &mut rng);
+                    let approx = graph.is_k_connected_approx(k);
+                    let exact = graph.is_k_connected_exact(k);
+                    samples += 1;
+
+                    if approx && !exact {
+                        false_positives.push(graph);
+                    } else if !approx && exact {
+                        false_negatives.push(graph);
+                    }
+                }
+            }
         }
 
-        // Expected properties for K_{1,4}
-        let is_star = star5.is_star();
-        let star_hamiltonian = star5.is_likely_hamiltonian(false);
-        let star_traceable = star5.is_likely_traceable(false);
+        (samples, false_positives, false_negatives)
+    }
 
-        println!("K_{{1,4}}: is_star={}, is_hamiltonian={}, is_traceable={}",
-                 is_star, star_hamiltonian, star_traceable);
+    fn check_error_rates(k: usize) {
+        let (samples, false_positives, false_negatives) = compare_approx_with_exact(k, 42);
 
-        assert!(is_star, "K_{{1,4}} should be identified as a star graph");
-        assert!(!star_hamiltonian, "K_{{1,4}} should not be identified as Hamiltonian");
-        assert!(star_traceable, "K_{{1,4}} should be identified as traceable");
+        for graph in &false_positives {
+            println!("False positive for k={}: {:?}", k, graph);
+        }
+        for graph in &false_negatives {
+            println!("False negative for k={}: {:?}", k, graph);
+        }
 
-        // 5. Petersen graph
-        let mut petersen = Graph::new(10);
-        // Add outer cycle
-        petersen.add_edge(0, 1).unwrap();
-        petersen.add_edge(1, 2).unwrap();
-        petersen.add_edge(2, 3).unwrap();
-        petersen.add_edge(3, 4).unwrap();
-        petersen.add_edge(4, 0).unwrap();
-        // Add spokes
-        petersen.add_edge(0, 5).unwrap();
-        petersen.add_edge(1, 6).unwrap();
-        petersen.add_edge(2, 7).unwrap();
-        petersen.add_edge(3, 8).unwrap();
-        petersen.add_edge(4, 9).unwrap();
-        // Add inner pentagram
-        petersen.add_edge(5, 7).unwrap();
-        petersen.add_edge(7, 9).unwrap();
-        petersen.add_edge(9, 6).unwrap();
-        petersen.add_edge(6, 8).unwrap();
-        petersen.add_edge(8, 5).unwrap();
+        let fp_rate = false_positives.len() as f64 / samples as f64;
+        let fn_rate = false_negatives.len() as f64 / samples as f64;
+        println!(
+            "k={}: {} samples, false positive rate {:.3}, false negative rate {:.3}",
+            k, samples, fp_rate, fn_rate
+        );
 
-        // Expected properties for Petersen graph
-        let is_petersen = petersen.is_petersen();
-        let petersen_hamiltonian = petersen.is_likely_hamiltonian(false);
-        let petersen_traceable = petersen.is_likely_traceable(false);
+        // The approximation only answers true on sufficient conditions, so it must never
+        // report a graph as k-connected when the exact check does not
+        assert!(
+            false_positives.is_empty(),
+            "False positive rate {:.3} for k={} should be zero",
+            fp_rate,
+            k
+        );
+        assert!(
+            fn_rate <= 0.05,
+            "False negative rate {:.3} for k={} exceeds 5%",
+            fn_rate,
+            k
+        );
+    }
 
-        println!("Petersen: is_petersen={}, is_hamiltonian={}, is_traceable={}",
-                 is_petersen, petersen_hamiltonian, petersen_traceable);
+    #[test]
+    fn test_approx_connectivity_error_rate_k2() {
+        check_error_rates(2);
+    }
 
-        // The Petersen graph is a famous counterexample - it's 3-regular, 3-connected,
-        // but not Hamiltonian. It is, however, traceable.
-        assert!(is_petersen, "Petersen graph should be identified as such");
+    #[test]
+    fn test_approx_connectivity_error_rate_k3() {
+        check_error_rates(3);
+    }
+}
 
-        // If the implementation has special handling for the Petersen graph:
-        if is_petersen {
-            assert!(!petersen_hamiltonian, "Petersen graph should not be identified as Hamiltonian");
-            assert!(petersen_traceable, "Petersen graph should be identified as traceable");
+#[cfg(test)]
+mod hamiltonicity_property_tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    /// Collect the graphs the heuristic calls Hamiltonian although no Hamiltonian cycle exists
+    fn false_positives(graphs: impl Iterator<Item = Graph>) -> Vec<Graph> {
+        graphs
+            .filter(|graph| {
+                graph.is_likely_hamiltonian_with(true) && graph.hamiltonian_cycle().is_none()
+            })
+            .collect()
+    }
+
+    fn assert_no_false_positives(false_positives: &[Graph], source: &str) {
+        for graph in false_positives {
+            println!("False positive ({}): {:?}", source, graph);
         }
 
-        // 6. Cube graph (Q_3)
-        let mut cube = Graph::new(8);
-        // Bottom face
-        cube.add_edge(0, 1).unwrap();
-        cube.add_edge(1, 2).unwrap();
-        cube.add_edge(2, 3).unwrap();
-        cube.add_edge(3, 0).unwrap();
-        // Top face
-        cube.add_edge(4, 5).unwrap();
-        cube.add_edge(5, 6).unwrap();
-        cube.add_edge(6, 7).unwrap();
-        cube.add_edge(7, 4).unwrap();
-        // Connecting edges
-        cube.add_edge(0, 4).unwrap();
-        cube.add_edge(1, 5).unwrap();
-        cube.add_edge(2, 6).unwrap();
-        cube.add_edge(3, 7).unwrap();
+        assert!(
+            false_positives.is_empty(),
+            "is_likely_hamiltonian reported {} non-Hamiltonian graphs from {} as Hamiltonian",
+            false_positives.len(),
+            source
+        );
+    }
 
-        // Expected properties for cube graph
-        let cube_hamiltonian = cube.is_likely_hamiltonian(false);
-        let cube_traceable = cube.is_likely_traceable(false);
-        let cube_z1 = cube.first_zagreb_index();
+    #[test]
+    fn test_complete_bipartite_exception() {
+        // K_{2,3} is 2-connected and meets the Theorem 1 threshold exactly, but Theorem 1
+        // excludes it and it has no Hamiltonian cycle
+        let k23 = Graph::from_edges(5, (0..2).flat_map(|u| (2..5).map(move |v| (u, v)))).unwrap();
+        assert_eq!(k23.first_zagreb_index(), k23.hamiltonian_threshold(2));
+        assert!(k23.hamiltonian_cycle().is_none());
+        assert!(!k23.is_likely_hamiltonian_with(true));
+        assert!(!k23.is_likely_hamiltonian_with(false));
+    }
 
-        println!("Cube graph: Zagreb index={}, is_hamiltonian={}, is_traceable={}",
-                 cube_z1, cube_hamiltonian, cube_traceable);
+    #[test]
+    fn test_no_false_positives_on_all_small_graphs() {
+        for n in 3..=6 {
+            let found = false_positives(Graph::all_graphs(n));
+            assert_no_false_positives(&found, &format!("all graphs on {} vertices", n));
+        }
+    }
 
-        // The cube graph is known to be Hamiltonian
-        // Note: We don't enforce this if the implementation approaches it differently
-        assert_eq!(cube_z1, 72, "Cube graph Zagreb index should be 8 * 3² = 72");
+    #[test]
+    fn test_no_false_positives_on_random_graphs() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for n in 7..=9 {
+            let mut samples = Vec::new();
+            for &p in &[0.4, 0.5, 0.6, 0.7, 0.8] {
+                for _ in 0..100 {
+                    let graph = Graph::erdos_renyi(n, p, &mut rng);
+                    // Dense samples and their complements cover both ends of the threshold
+                    samples.push(graph.complement());
+                    samples.push(graph);
+                }
+            }
 
-        // Print whether the implementation identifies it as Hamiltonian
-        println!("Implementation identifies cube graph as Hamiltonian: {}", cube_hamiltonian);
+            let found = false_positives(samples.into_iter());
+            assert_no_false_positives(&found, &format!("random graphs on {} vertices", n));
+        }
     }
 }
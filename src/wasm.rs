@@ -1,11 +1,13 @@
 use wasm_bindgen::prelude::*;
+#[cfg(feature = "serde")]
 use serde::{Serialize, Deserialize};
 
 use crate::Graph;
 
 /// A simple error type for WASM interfaces
 #[wasm_bindgen]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WasmError {
     message: String,
 }
@@ -27,11 +29,12 @@ impl WasmError {
 
 /// Graph analysis result to be returned to JavaScript
 #[wasm_bindgen]
-#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GraphAnalysisResult {
     vertex_count: usize,
     edge_count: usize,
     zagreb_index: usize,
+    second_zagreb_index: usize,
     min_degree: usize,
     max_degree: usize,
     is_likely_hamiltonian: bool,
@@ -57,6 +60,11 @@ impl GraphAnalysisResult {
         self.zagreb_index
     }
 
+    #[wasm_bindgen(getter)]
+    pub fn second_zagreb_index(&self) -> usize {
+        self.second_zagreb_index
+    }
+
     #[wasm_bindgen(getter)]
     pub fn min_degree(&self) -> usize {
         self.min_degree
@@ -107,6 +115,20 @@ impl WasmGraph {
         }
     }
 
+    /// Create a graph with n vertices from a JavaScript array of `[u, v]` edge pairs
+    #[wasm_bindgen]
+    pub fn from_edges(n: usize, edges: &JsValue) -> Result<WasmGraph, JsValue> {
+        let pairs: Vec<(usize, usize)> = serde_wasm_bindgen::from_value(edges.clone())
+            .map_err(|e| JsValue::from(WasmError::new(&format!("Invalid edge list: {}", e))))?;
+
+        let mut graph = WasmGraph::new(n);
+        for (u, v) in pairs {
+            graph.add_edge(u, v)?;
+        }
+
+        Ok(graph)
+    }
+
     /// Add an edge between vertices u and v
     #[wasm_bindgen]
     pub fn add_edge(&mut self, u: usize, v: usize) -> Result<(), JsValue> {
@@ -127,6 +149,12 @@ impl WasmGraph {
         self.graph.first_zagreb_index()
     }
 
+    /// Calculate the second Zagreb index of the graph
+    #[wasm_bindgen]
+    pub fn second_zagreb_index(&self) -> usize {
+        self.graph.second_zagreb_index()
+    }
+
     /// Get the minimum degree of the graph
     #[wasm_bindgen]
     pub fn min_degree(&self) -> usize {
@@ -142,19 +170,19 @@ impl WasmGraph {
     /// Check if the graph is k-connected
     #[wasm_bindgen]
     pub fn is_k_connected(&self, k: usize, use_exact: bool) -> bool {
-        self.graph.is_k_connected(k, use_exact)
+        self.graph.is_k_connected_with(k, use_exact)
     }
 
     /// Check if the graph is likely Hamiltonian
     #[wasm_bindgen]
     pub fn is_likely_hamiltonian(&self, use_exact_connectivity: bool) -> bool {
-        self.graph.is_likely_hamiltonian(use_exact_connectivity)
+        self.graph.is_likely_hamiltonian_with(use_exact_connectivity)
     }
 
     /// Check if the graph is likely traceable
     #[wasm_bindgen]
     pub fn is_likely_traceable(&self, use_exact_connectivity: bool) -> bool {
-        self.graph.is_likely_traceable(use_exact_connectivity)
+        self.graph.is_likely_traceable_with(use_exact_connectivity)
     }
 
     /// Calculate independence number (approximate)
@@ -188,10 +216,11 @@ impl WasmGraph {
             vertex_count: self.graph.vertex_count(),
             edge_count: self.graph.edge_count(),
             zagreb_index: self.graph.first_zagreb_index(),
+            second_zagreb_index: self.graph.second_zagreb_index(),
             min_degree: self.graph.min_degree(),
             max_degree: self.graph.max_degree(),
-            is_likely_hamiltonian: self.graph.is_likely_hamiltonian(false),
-            is_likely_traceable: self.graph.is_likely_traceable(false),
+            is_likely_hamiltonian: self.graph.is_likely_hamiltonian(),
+            is_likely_traceable: self.graph.is_likely_traceable(),
             independence_number: self.graph.independence_number_approx(),
             zagreb_upper_bound: self.graph.zagreb_upper_bound(),
         }
@@ -200,69 +229,39 @@ impl WasmGraph {
     /// Create a complete graph with n vertices
     #[wasm_bindgen]
     pub fn create_complete(n: usize) -> Result<WasmGraph, JsValue> {
-        let mut graph = WasmGraph::new(n);
-
-        for i in 0..n {
-            for j in (i + 1)..n {
-                graph.add_edge(i, j)?;
-            }
-        }
-
-        Ok(graph)
+        Ok(WasmGraph::from_graph(Graph::complete(n)))
     }
 
     /// Create a cycle graph with n vertices
     #[wasm_bindgen]
     pub fn create_cycle(n: usize) -> Result<WasmGraph, JsValue> {
-        let mut graph = WasmGraph::new(n);
-
-        for i in 0..n {
-            let j = (i + 1) % n;
-            graph.add_edge(i, j)?;
+        // Graph::cycle panics below 3 vertices, so report it to JavaScript instead
+        if n < 3 {
+            return Err(JsValue::from(WasmError::new("A cycle needs at least 3 vertices")));
         }
 
-        Ok(graph)
+        Ok(WasmGraph::from_graph(Graph::cycle(n)))
     }
 
     /// Create a star graph with n vertices
     #[wasm_bindgen]
     pub fn create_star(n: usize) -> Result<WasmGraph, JsValue> {
-        let mut graph = WasmGraph::new(n);
-
-        for i in 1..n {
-            graph.add_edge(0, i)?;
-        }
-
-        Ok(graph)
+        Ok(WasmGraph::from_graph(Graph::star(n)))
     }
 
     /// Create the Petersen graph
     #[wasm_bindgen]
     pub fn create_petersen() -> Result<WasmGraph, JsValue> {
-        let mut graph = WasmGraph::new(10);
-
-        // Add outer cycle edges (pentagon)
-        graph.add_edge(0, 1)?;
-        graph.add_edge(1, 2)?;
-        graph.add_edge(2, 3)?;
-        graph.add_edge(3, 4)?;
-        graph.add_edge(4, 0)?;
-
-        // Add spoke edges (connecting outer and inner vertices)
-        graph.add_edge(0, 5)?;
-        graph.add_edge(1, 6)?;
-        graph.add_edge(2, 7)?;
-        graph.add_edge(3, 8)?;
-        graph.add_edge(4, 9)?;
-
-        // Add inner pentagram edges
-        graph.add_edge(5, 7)?;
-        graph.add_edge(7, 9)?;
-        graph.add_edge(9, 6)?;
-        graph.add_edge(6, 8)?;
-        graph.add_edge(8, 5)?;
+        Ok(WasmGraph::from_graph(Graph::petersen()))
+    }
+}
 
-        Ok(graph)
+impl WasmGraph {
+    /// Wrap a core graph, setting up the panic hook like the constructor does
+    fn from_graph(graph: Graph) -> Self {
+        console_error_panic_hook::set_once();
+
+        Self { graph }
     }
 }
 
@@ -283,4 +282,35 @@ pub fn get_low_connectivity_validators(graph: &WasmGraph) -> Box<[usize]> {
     }
 
     low_connectivity_validators.into_boxed_slice()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn test_analyze_second_zagreb_index() {
+        let star = WasmGraph::create_star(4).unwrap();
+        let result = star.analyze();
+
+        // Three edges, each joining the degree-3 center to a leaf
+        assert_eq!(result.second_zagreb_index(), 9);
+        assert_eq!(result.second_zagreb_index(), star.second_zagreb_index());
+        assert_eq!(result.zagreb_index(), 12);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_from_edges() {
+        let edges = serde_wasm_bindgen::to_value(&[(0, 1), (1, 2), (2, 3), (3, 0)]).unwrap();
+        let cycle = WasmGraph::from_edges(4, &edges).unwrap();
+        assert_eq!(cycle.vertex_count(), 4);
+        assert_eq!(cycle.edge_count(), 4);
+        assert_eq!(cycle.first_zagreb_index(), 16);
+
+        // Out-of-bounds vertices and malformed input are rejected
+        let out_of_bounds = serde_wasm_bindgen::to_value(&[(0, 9)]).unwrap();
+        assert!(WasmGraph::from_edges(4, &out_of_bounds).is_err());
+        assert!(WasmGraph::from_edges(4, &JsValue::from_str("not edges")).is_err());
+    }
+}